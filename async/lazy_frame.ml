@@ -10,3 +10,59 @@ let profile t = In_thread.run (fun () -> profile t)
 let profile_exn t = In_thread.run (fun () -> profile_exn t)
 let fetch t ~n_rows = In_thread.run (fun () -> fetch t ~n_rows)
 let fetch_exn t ~n_rows = In_thread.run (fun () -> fetch_exn t ~n_rows)
+
+let sink_parquet
+  ?compression
+  ?statistics
+  ?row_group_size
+  ?data_page_size
+  ?maintain_order
+  t
+  path
+  =
+  In_thread.run (fun () ->
+    sink_parquet
+      ?compression
+      ?statistics
+      ?row_group_size
+      ?data_page_size
+      ?maintain_order
+      t
+      path)
+;;
+
+let sink_parquet_exn
+  ?compression
+  ?statistics
+  ?row_group_size
+  ?data_page_size
+  ?maintain_order
+  t
+  path
+  =
+  In_thread.run (fun () ->
+    sink_parquet_exn
+      ?compression
+      ?statistics
+      ?row_group_size
+      ?data_page_size
+      ?maintain_order
+      t
+      path)
+;;
+
+let sink_ipc ?compression ?maintain_order t path =
+  In_thread.run (fun () -> sink_ipc ?compression ?maintain_order t path)
+;;
+
+let sink_ipc_exn ?compression ?maintain_order t path =
+  In_thread.run (fun () -> sink_ipc_exn ?compression ?maintain_order t path)
+;;
+
+let write_partitioned ?format t ~partition_by root =
+  In_thread.run (fun () -> write_partitioned ?format t ~partition_by root)
+;;
+
+let write_partitioned_exn ?format t ~partition_by root =
+  In_thread.run (fun () -> write_partitioned_exn ?format t ~partition_by root)
+;;
@@ -11,3 +11,51 @@ val profile : t -> (profile_result, string) result Deferred.t
 val profile_exn : t -> profile_result Deferred.t
 val fetch : t -> n_rows:int -> (Data_frame.t, string) result Deferred.t
 val fetch_exn : t -> n_rows:int -> Data_frame.t Deferred.t
+
+val sink_parquet
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> (unit, string) result Deferred.t
+
+val sink_parquet_exn
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> unit Deferred.t
+
+val sink_ipc
+  :  ?compression:[ `Lz4 | `Zstd ]
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> (unit, string) result Deferred.t
+
+val sink_ipc_exn
+  :  ?compression:[ `Lz4 | `Zstd ]
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> unit Deferred.t
+
+val write_partitioned
+  :  ?format:[ `Csv | `Ipc | `Parquet ]
+  -> t
+  -> partition_by:string list
+  -> string
+  -> ((string * int) list, string) result Deferred.t
+
+val write_partitioned_exn
+  :  ?format:[ `Csv | `Ipc | `Parquet ]
+  -> t
+  -> partition_by:string list
+  -> string
+  -> (string * int) list Deferred.t
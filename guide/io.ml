@@ -79,7 +79,7 @@ let%expect_test "Parquet" =
       │ 2   ┆ bak  │
       │ 3   ┆ baz  │
       └─────┴──────┘ |}];
-    Data_frame.write_parquet_exn df path;
+    let (_ : int) = Data_frame.write_parquet_exn df path in
     Data_frame.read_parquet_exn path |> Data_frame.print;
     [%expect
       {|
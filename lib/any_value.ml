@@ -0,0 +1,25 @@
+open! Core
+
+type t =
+  | Null
+  | Boolean of bool
+  | UInt8 of int
+  | UInt16 of int
+  | UInt32 of int
+  | UInt64 of int
+  | Int8 of int
+  | Int16 of int
+  | Int32 of int
+  | Int64 of int
+  | Float32 of float
+  | Float64 of float
+  | Utf8 of string
+  | Binary of string
+  | Date of Common.Naive_date.t
+  | Datetime of Common.Naive_datetime.t * Data_type.Time_unit.t * string option
+  | Duration of Time_ns.Span.t * Data_type.Time_unit.t
+  | Time of Time_ns.Ofday.t
+  | List of t list
+  | Struct of (string * t) list
+  | Decimal of int * int
+[@@deriving sexp_of]
@@ -0,0 +1,31 @@
+open! Core
+
+(** A single value of any data type, for when the data type of a column isn't known
+    in advance. Use [Data_type.Typed] to get values of a known data type instead. *)
+type t =
+  | Null
+  | Boolean of bool
+  | UInt8 of int
+  | UInt16 of int
+  | UInt32 of int
+  | UInt64 of int
+  | Int8 of int
+  | Int16 of int
+  | Int32 of int
+  | Int64 of int
+  | Float32 of float
+  | Float64 of float
+  | Utf8 of string
+  (** Values of [Categorical] columns are also [Utf8]. *)
+  | Binary of string
+  | Date of Common.Naive_date.t
+  | Datetime of Common.Naive_datetime.t * Data_type.Time_unit.t * string option
+  (** The datetime (in UTC if there is a time zone), its time unit and time zone. *)
+  | Duration of Time_ns.Span.t * Data_type.Time_unit.t
+  | Time of Time_ns.Ofday.t
+  | List of t list
+  (** Values of fixed-size [Array] columns are also [List]s. *)
+  | Struct of (string * t) list
+  | Decimal of int * int
+  (** The value scaled by [10 ** scale], and the scale. *)
+[@@deriving sexp_of]
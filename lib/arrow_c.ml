@@ -0,0 +1,21 @@
+open! Core
+
+type kind =
+  [ `Schema
+  | `Array
+  | `Stream
+  ]
+
+external allocate : kind -> int = "rust_arrow_c_allocate"
+external free : kind -> int -> (unit, string) result = "rust_arrow_c_free"
+
+let allocate kind = allocate kind |> Nativeint.of_int
+
+let free kind address =
+  free kind (Nativeint.to_int_exn address) |> Utils.string_result_ok_exn
+;;
+
+let with_ kind ~f =
+  let address = allocate kind in
+  Exn.protect ~f:(fun () -> f address) ~finally:(fun () -> free kind address)
+;;
@@ -0,0 +1,22 @@
+open! Core
+
+(** The Arrow C Data and Stream Interfaces leave allocating the [ArrowSchema],
+    [ArrowArray] and [ArrowArrayStream] structs to the consumer. When the other side
+    doesn't provide them itself, they can be allocated here and their addresses passed
+    to e.g. [Series.export_to_c] and [Data_frame.import_stream_from_c]. *)
+
+type kind =
+  [ `Schema
+  | `Array
+  | `Stream
+  ]
+
+(** Returns the address of a newly allocated, empty struct of the given kind. *)
+val allocate : kind -> nativeint
+
+(** Frees a struct returned by [allocate], first calling its [release] callback if it
+    still holds exported data which was never imported. *)
+val free : kind -> nativeint -> unit
+
+(** [with_ kind ~f] allocates a struct for the duration of [f]. *)
+val with_ : kind -> f:(nativeint -> 'a) -> 'a
@@ -35,6 +35,10 @@ module Naive_date = struct
   ;;
 
   let of_string str = Date.of_string str |> of_date
+
+  external to_string : t -> string = "rust_naive_date_to_string"
+
+  let sexp_of_t t = to_string t |> [%sexp_of: string]
 end
 
 module Naive_datetime = struct
@@ -58,6 +62,8 @@ module Naive_datetime = struct
 
   external to_string : t -> string = "rust_naive_datetime_to_string"
 
+  let sexp_of_t t = to_string t |> [%sexp_of: string]
+
   let of_string str =
     let naive_date, hour, min, sec =
       match String.split str ~on:' ' with
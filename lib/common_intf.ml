@@ -56,15 +56,16 @@ module type Common = sig
   module Make_logic (T : Logic_basic) : Logic with type t := T.t
 
   module Naive_date : sig
-    type t
+    type t [@@deriving sexp_of]
 
     val create : year:int -> month:int -> day:int -> t option
     val of_date : Date.t -> t
     val of_string : string -> t
+    val to_string : t -> string
   end
 
   module Naive_datetime : sig
-    type t
+    type t [@@deriving sexp_of]
 
     val of_naive_date : ?hour:int -> ?min:int -> ?sec:int -> Naive_date.t -> t
     val of_date : ?hour:int -> ?min:int -> ?sec:int -> Date.t -> t
@@ -2,59 +2,873 @@ open! Core
 
 type t = Data_frame0.t
 
+type in_memory =
+  [ `Bigstring of Bigstring.t
+  | `Bytes of bytes
+  | `String of string
+  ]
+
+type read_source =
+  [ `Path of string
+  | in_memory
+  ]
+
+type write_target =
+  [ `Path of string
+  | `Buffer
+  ]
+
+let in_memory_source (buffer : in_memory) = (buffer :> read_source)
+
 external create : Series.t list -> (t, string) result = "rust_data_frame_new"
 
 let create_exn series = create series |> Utils.string_result_ok_exn
 
+external of_rows
+  :  Any_value.t list list
+  -> schema:Schema.t option
+  -> (t, string) result
+  = "rust_data_frame_of_rows"
+
+let of_rows ?schema rows = of_rows rows ~schema
+let of_rows_exn ?schema rows = of_rows ?schema rows |> Utils.string_result_ok_exn
+
 external read_csv
-  :  string
+  :  read_source
   -> schema:Schema.t option
   -> try_parse_dates:bool option
+  -> separator:int option
+  -> has_header:bool option
+  -> skip_rows:int option
+  -> comment_char:int option
+  -> quote_char:int option
+  -> null_values:Null_values.t option
+  -> n_rows:int option
+  -> columns:string list option
+  -> projection:int list option
+  -> infer_schema_length:int option
+  -> ignore_errors:bool option
+  -> encoding:[ `Utf8 | `Lossy_utf8 ] option
+  -> low_memory:bool option
+  -> rechunk:bool option
   -> (t, string) result
-  = "rust_data_frame_read_csv"
+  = "rust_data_frame_read_csv_bytecode" "rust_data_frame_read_csv"
+
+let read_csv_source
+  ?schema
+  ?try_parse_dates
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?columns
+  ?projection
+  ?infer_schema_length
+  ?ignore_errors
+  ?encoding
+  ?low_memory
+  ?rechunk
+  source
+  =
+  read_csv
+    source
+    ~schema
+    ~try_parse_dates
+    ~separator:(Option.map separator ~f:Char.to_int)
+    ~has_header
+    ~skip_rows
+    ~comment_char:(Option.map comment_char ~f:Char.to_int)
+    ~quote_char:(Option.map quote_char ~f:Char.to_int)
+    ~null_values
+    ~n_rows
+    ~columns
+    ~projection
+    ~infer_schema_length
+    ~ignore_errors
+    ~encoding
+    ~low_memory
+    ~rechunk
+;;
+
+let read_csv
+  ?schema
+  ?try_parse_dates
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?columns
+  ?projection
+  ?infer_schema_length
+  ?ignore_errors
+  ?encoding
+  ?low_memory
+  ?rechunk
+  path
+  =
+  read_csv_source
+    ?schema
+    ?try_parse_dates
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?columns
+    ?projection
+    ?infer_schema_length
+    ?ignore_errors
+    ?encoding
+    ?low_memory
+    ?rechunk
+    (`Path path)
+;;
+
+let read_csv_exn
+  ?schema
+  ?try_parse_dates
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?columns
+  ?projection
+  ?infer_schema_length
+  ?ignore_errors
+  ?encoding
+  ?low_memory
+  ?rechunk
+  path
+  =
+  read_csv
+    ?schema
+    ?try_parse_dates
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?columns
+    ?projection
+    ?infer_schema_length
+    ?ignore_errors
+    ?encoding
+    ?low_memory
+    ?rechunk
+    path
+  |> Utils.string_result_ok_exn
+;;
+
+let read_csv_from_memory
+  ?schema
+  ?try_parse_dates
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?columns
+  ?projection
+  ?infer_schema_length
+  ?ignore_errors
+  ?encoding
+  ?low_memory
+  ?rechunk
+  buffer
+  =
+  read_csv_source
+    ?schema
+    ?try_parse_dates
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?columns
+    ?projection
+    ?infer_schema_length
+    ?ignore_errors
+    ?encoding
+    ?low_memory
+    ?rechunk
+    (in_memory_source buffer)
+;;
+
+let read_csv_from_memory_exn
+  ?schema
+  ?try_parse_dates
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?columns
+  ?projection
+  ?infer_schema_length
+  ?ignore_errors
+  ?encoding
+  ?low_memory
+  ?rechunk
+  buffer
+  =
+  read_csv_from_memory
+    ?schema
+    ?try_parse_dates
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?columns
+    ?projection
+    ?infer_schema_length
+    ?ignore_errors
+    ?encoding
+    ?low_memory
+    ?rechunk
+    buffer
+  |> Utils.string_result_ok_exn
+;;
+
+external write_csv
+  :  t
+  -> write_target
+  -> has_header:bool
+  -> separator:int
+  -> quote_char:int
+  -> quote_style:[ `Always | `Necessary | `Non_numeric ]
+  -> null_value:string
+  -> line_terminator:string
+  -> float_precision:int option
+  -> date_format:string option
+  -> datetime_format:string option
+  -> time_format:string option
+  -> (string option, string) result
+  = "rust_data_frame_write_csv_bytecode" "rust_data_frame_write_csv"
+
+let write_csv_target
+  ?(has_header = true)
+  ?(separator = ',')
+  ?(quote_char = '"')
+  ?(quote_style = `Necessary)
+  ?(null_value = "")
+  ?(line_terminator = "\n")
+  ?float_precision
+  ?date_format
+  ?datetime_format
+  ?time_format
+  t
+  target
+  =
+  write_csv
+    t
+    target
+    ~has_header
+    ~separator:(Char.to_int separator)
+    ~quote_char:(Char.to_int quote_char)
+    ~quote_style
+    ~null_value
+    ~line_terminator
+    ~float_precision
+    ~date_format
+    ~datetime_format
+    ~time_format
+;;
 
-let read_csv ?schema ?try_parse_dates path = read_csv path ~schema ~try_parse_dates
+let write_csv
+  ?has_header
+  ?separator
+  ?quote_char
+  ?quote_style
+  ?null_value
+  ?line_terminator
+  ?float_precision
+  ?date_format
+  ?datetime_format
+  ?time_format
+  t
+  path
+  =
+  write_csv_target
+    ?has_header
+    ?separator
+    ?quote_char
+    ?quote_style
+    ?null_value
+    ?line_terminator
+    ?float_precision
+    ?date_format
+    ?datetime_format
+    ?time_format
+    t
+    (`Path path)
+  |> Result.map ~f:(ignore : string option -> unit)
+;;
 
-let read_csv_exn ?schema ?try_parse_dates path =
-  read_csv ?schema ?try_parse_dates path |> Utils.string_result_ok_exn
+let write_csv_exn
+  ?has_header
+  ?separator
+  ?quote_char
+  ?quote_style
+  ?null_value
+  ?line_terminator
+  ?float_precision
+  ?date_format
+  ?datetime_format
+  ?time_format
+  t
+  path
+  =
+  write_csv
+    ?has_header
+    ?separator
+    ?quote_char
+    ?quote_style
+    ?null_value
+    ?line_terminator
+    ?float_precision
+    ?date_format
+    ?datetime_format
+    ?time_format
+    t
+    path
+  |> Utils.string_result_ok_exn
 ;;
 
-external write_csv : t -> string -> (unit, string) result = "rust_data_frame_write_csv"
+let write_csv_to_string
+  ?has_header
+  ?separator
+  ?quote_char
+  ?quote_style
+  ?null_value
+  ?line_terminator
+  ?float_precision
+  ?date_format
+  ?datetime_format
+  ?time_format
+  t
+  =
+  write_csv_target
+    ?has_header
+    ?separator
+    ?quote_char
+    ?quote_style
+    ?null_value
+    ?line_terminator
+    ?float_precision
+    ?date_format
+    ?datetime_format
+    ?time_format
+    t
+    `Buffer
+  |> Result.map ~f:(fun buffer -> Option.value_exn buffer)
+;;
 
-let write_csv_exn t path = write_csv t path |> Utils.string_result_ok_exn
+let write_csv_to_string_exn
+  ?has_header
+  ?separator
+  ?quote_char
+  ?quote_style
+  ?null_value
+  ?line_terminator
+  ?float_precision
+  ?date_format
+  ?datetime_format
+  ?time_format
+  t
+  =
+  write_csv_to_string
+    ?has_header
+    ?separator
+    ?quote_char
+    ?quote_style
+    ?null_value
+    ?line_terminator
+    ?float_precision
+    ?date_format
+    ?datetime_format
+    ?time_format
+    t
+  |> Utils.string_result_ok_exn
+;;
 
-external read_parquet : string -> (t, string) result = "rust_data_frame_read_parquet"
+external read_parquet
+  :  read_source
+  -> columns:string list option
+  -> projection:int list option
+  -> n_rows:int option
+  -> row_count:(string * int) option
+  -> parallel:[ `Auto | `Columns | `None | `Row_groups ] option
+  -> low_memory:bool option
+  -> rechunk:bool option
+  -> (t, string) result
+  = "rust_data_frame_read_parquet_bytecode" "rust_data_frame_read_parquet"
+
+let read_parquet_source
+  ?columns
+  ?projection
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  source
+  =
+  read_parquet
+    source
+    ~columns
+    ~projection
+    ~n_rows
+    ~row_count
+    ~parallel
+    ~low_memory
+    ~rechunk
+;;
 
-let read_parquet_exn path = read_parquet path |> Utils.string_result_ok_exn
+let read_parquet
+  ?columns
+  ?projection
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  path
+  =
+  read_parquet_source
+    ?columns
+    ?projection
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    (`Path path)
+;;
+
+let read_parquet_exn
+  ?columns
+  ?projection
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  path
+  =
+  read_parquet
+    ?columns
+    ?projection
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    path
+  |> Utils.string_result_ok_exn
+;;
+
+let read_parquet_from_memory
+  ?columns
+  ?projection
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  buffer
+  =
+  read_parquet_source
+    ?columns
+    ?projection
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    (in_memory_source buffer)
+;;
+
+let read_parquet_from_memory_exn
+  ?columns
+  ?projection
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  buffer
+  =
+  read_parquet_from_memory
+    ?columns
+    ?projection
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    buffer
+  |> Utils.string_result_ok_exn
+;;
 
 external write_parquet
   :  t
-  -> string
-  -> (unit, string) result
-  = "rust_data_frame_write_parquet"
+  -> write_target
+  -> compression:Parquet_compression.t
+  -> statistics:bool
+  -> row_group_size:int option
+  -> data_page_size:int option
+  -> (int * string option, string) result
+  = "rust_data_frame_write_parquet_bytecode" "rust_data_frame_write_parquet"
+
+let write_parquet_target
+  ?(compression = Parquet_compression.Zstd None)
+  ?(statistics = false)
+  ?row_group_size
+  ?data_page_size
+  t
+  target
+  =
+  write_parquet t target ~compression ~statistics ~row_group_size ~data_page_size
+;;
 
-let write_parquet_exn t path = write_parquet t path |> Utils.string_result_ok_exn
+let write_parquet ?compression ?statistics ?row_group_size ?data_page_size t path =
+  write_parquet_target
+    ?compression
+    ?statistics
+    ?row_group_size
+    ?data_page_size
+    t
+    (`Path path)
+  |> Result.map ~f:fst
+;;
+
+let write_parquet_exn ?compression ?statistics ?row_group_size ?data_page_size t path =
+  write_parquet ?compression ?statistics ?row_group_size ?data_page_size t path
+  |> Utils.string_result_ok_exn
+;;
 
-external read_json : string -> (t, string) result = "rust_data_frame_read_json"
+let write_parquet_to_string ?compression ?statistics ?row_group_size ?data_page_size t =
+  write_parquet_target ?compression ?statistics ?row_group_size ?data_page_size t `Buffer
+  |> Result.map ~f:(fun (_file_size, buffer) -> Option.value_exn buffer)
+;;
+
+let write_parquet_to_string_exn
+  ?compression
+  ?statistics
+  ?row_group_size
+  ?data_page_size
+  t
+  =
+  write_parquet_to_string ?compression ?statistics ?row_group_size ?data_page_size t
+  |> Utils.string_result_ok_exn
+;;
+
+external read_json_source
+  :  read_source
+  -> (t, string) result
+  = "rust_data_frame_read_json"
+
+let read_json path = read_json_source (`Path path)
 
 let read_json_exn path = read_json path |> Utils.string_result_ok_exn
 
-external write_json : t -> string -> (unit, string) result = "rust_data_frame_write_json"
+let read_json_from_memory buffer = read_json_source (in_memory_source buffer)
+
+let read_json_from_memory_exn buffer =
+  read_json_from_memory buffer |> Utils.string_result_ok_exn
+;;
+
+external write_json_target
+  :  t
+  -> write_target
+  -> (string option, string) result
+  = "rust_data_frame_write_json"
+
+let write_json t path =
+  write_json_target t (`Path path) |> Result.map ~f:(ignore : string option -> unit)
+;;
 
 let write_json_exn t path = write_json t path |> Utils.string_result_ok_exn
 
-external read_jsonl : string -> (t, string) result = "rust_data_frame_read_jsonl"
+let write_json_to_string t =
+  write_json_target t `Buffer |> Result.map ~f:(fun buffer -> Option.value_exn buffer)
+;;
+
+let write_json_to_string_exn t = write_json_to_string t |> Utils.string_result_ok_exn
+
+external read_jsonl_source
+  :  read_source
+  -> (t, string) result
+  = "rust_data_frame_read_jsonl"
+
+let read_jsonl path = read_jsonl_source (`Path path)
 
 let read_jsonl_exn path = read_jsonl path |> Utils.string_result_ok_exn
 
-external write_jsonl
+let read_jsonl_from_memory buffer = read_jsonl_source (in_memory_source buffer)
+
+let read_jsonl_from_memory_exn buffer =
+  read_jsonl_from_memory buffer |> Utils.string_result_ok_exn
+;;
+
+external write_jsonl_target
   :  t
-  -> string
-  -> (unit, string) result
+  -> write_target
+  -> (string option, string) result
   = "rust_data_frame_write_jsonl"
 
+let write_jsonl t path =
+  write_jsonl_target t (`Path path) |> Result.map ~f:(ignore : string option -> unit)
+;;
+
 let write_jsonl_exn t path = write_jsonl t path |> Utils.string_result_ok_exn
 
+let write_jsonl_to_string t =
+  write_jsonl_target t `Buffer |> Result.map ~f:(fun buffer -> Option.value_exn buffer)
+;;
+
+let write_jsonl_to_string_exn t = write_jsonl_to_string t |> Utils.string_result_ok_exn
+
+external read_ipc
+  :  read_source
+  -> columns:string list option
+  -> projection:int list option
+  -> n_rows:int option
+  -> row_count:(string * int) option
+  -> memory_map:bool option
+  -> rechunk:bool option
+  -> (t, string) result
+  = "rust_data_frame_read_ipc_bytecode" "rust_data_frame_read_ipc"
+
+let read_ipc_source ?columns ?projection ?n_rows ?row_count ?memory_map ?rechunk source =
+  read_ipc source ~columns ~projection ~n_rows ~row_count ~memory_map ~rechunk
+;;
+
+let read_ipc ?columns ?projection ?n_rows ?row_count ?memory_map ?rechunk path =
+  read_ipc_source
+    ?columns
+    ?projection
+    ?n_rows
+    ?row_count
+    ?memory_map
+    ?rechunk
+    (`Path path)
+;;
+
+let read_ipc_exn ?columns ?projection ?n_rows ?row_count ?memory_map ?rechunk path =
+  read_ipc ?columns ?projection ?n_rows ?row_count ?memory_map ?rechunk path
+  |> Utils.string_result_ok_exn
+;;
+
+let read_ipc_from_memory ?columns ?projection ?n_rows ?row_count ?rechunk buffer =
+  read_ipc_source
+    ?columns
+    ?projection
+    ?n_rows
+    ?row_count
+    ?rechunk
+    (in_memory_source buffer)
+;;
+
+let read_ipc_from_memory_exn ?columns ?projection ?n_rows ?row_count ?rechunk buffer =
+  read_ipc_from_memory ?columns ?projection ?n_rows ?row_count ?rechunk buffer
+  |> Utils.string_result_ok_exn
+;;
+
+external write_ipc_target
+  :  t
+  -> write_target
+  -> compression:[ `Lz4 | `Zstd ] option
+  -> (string option, string) result
+  = "rust_data_frame_write_ipc"
+
+let write_ipc ?compression t path =
+  write_ipc_target t (`Path path) ~compression
+  |> Result.map ~f:(ignore : string option -> unit)
+;;
+
+let write_ipc_exn ?compression t path =
+  write_ipc ?compression t path |> Utils.string_result_ok_exn
+;;
+
+let write_ipc_to_string ?compression t =
+  write_ipc_target t `Buffer ~compression
+  |> Result.map ~f:(fun buffer -> Option.value_exn buffer)
+;;
+
+let write_ipc_to_string_exn ?compression t =
+  write_ipc_to_string ?compression t |> Utils.string_result_ok_exn
+;;
+
+external write_ipc_stream
+  :  t
+  -> Core_unix.File_descr.t
+  -> compression:[ `Lz4 | `Zstd ] option
+  -> (unit, string) result
+  = "rust_data_frame_write_ipc_stream"
+
+let write_ipc_stream ?compression t fd = write_ipc_stream t fd ~compression
+
+let write_ipc_stream_exn ?compression t fd =
+  write_ipc_stream ?compression t fd |> Utils.string_result_ok_exn
+;;
+
+external read_ipc_stream
+  :  Core_unix.File_descr.t
+  -> columns:string list option
+  -> projection:int list option
+  -> n_rows:int option
+  -> row_count:(string * int) option
+  -> rechunk:bool option
+  -> (t option, string) result
+  = "rust_data_frame_read_ipc_stream_bytecode" "rust_data_frame_read_ipc_stream"
+
+let read_ipc_stream ?columns ?projection ?n_rows ?row_count ?rechunk fd =
+  read_ipc_stream fd ~columns ~projection ~n_rows ~row_count ~rechunk
+;;
+
+let read_ipc_stream_exn ?columns ?projection ?n_rows ?row_count ?rechunk fd =
+  read_ipc_stream ?columns ?projection ?n_rows ?row_count ?rechunk fd
+  |> Utils.string_result_ok_exn
+;;
+
+external write_partitioned
+  :  t
+  -> string
+  -> partition_by:string list
+  -> format:[ `Csv | `Ipc | `Parquet ]
+  -> compression:Parquet_compression.t
+  -> statistics:bool
+  -> row_group_size:int option
+  -> data_page_size:int option
+  -> ((string * int) list, string) result
+  = "rust_data_frame_write_partitioned_bytecode" "rust_data_frame_write_partitioned"
+
+let write_partitioned
+  ?(format = `Parquet)
+  ?(compression = Parquet_compression.Zstd None)
+  ?(statistics = false)
+  ?row_group_size
+  ?data_page_size
+  t
+  ~partition_by
+  root
+  =
+  write_partitioned
+    t
+    root
+    ~partition_by
+    ~format
+    ~compression
+    ~statistics
+    ~row_group_size
+    ~data_page_size
+;;
+
+let write_partitioned_exn
+  ?format
+  ?compression
+  ?statistics
+  ?row_group_size
+  ?data_page_size
+  t
+  ~partition_by
+  root
+  =
+  write_partitioned
+    ?format
+    ?compression
+    ?statistics
+    ?row_group_size
+    ?data_page_size
+    t
+    ~partition_by
+    root
+  |> Utils.string_result_ok_exn
+;;
+
+external export_to_c
+  :  t
+  -> schema:int
+  -> array:int
+  -> (unit, string) result
+  = "rust_data_frame_export_to_c"
+
+let export_to_c t ~schema ~array =
+  let schema = Nativeint.to_int_exn schema in
+  let array = Nativeint.to_int_exn array in
+  export_to_c t ~schema ~array
+;;
+
+let export_to_c_exn t ~schema ~array =
+  export_to_c t ~schema ~array |> Utils.string_result_ok_exn
+;;
+
+external import_from_c
+  :  schema:int
+  -> array:int
+  -> (t, string) result
+  = "rust_data_frame_import_from_c"
+
+let import_from_c ~schema ~array =
+  let schema = Nativeint.to_int_exn schema in
+  let array = Nativeint.to_int_exn array in
+  import_from_c ~schema ~array
+;;
+
+let import_from_c_exn ~schema ~array =
+  import_from_c ~schema ~array |> Utils.string_result_ok_exn
+;;
+
+external export_stream_to_c
+  :  t
+  -> stream:int
+  -> (unit, string) result
+  = "rust_data_frame_export_stream_to_c"
+
+let export_stream_to_c t ~stream =
+  export_stream_to_c t ~stream:(Nativeint.to_int_exn stream)
+;;
+
+let export_stream_to_c_exn t ~stream =
+  export_stream_to_c t ~stream |> Utils.string_result_ok_exn
+;;
+
+external import_stream_from_c
+  :  stream:int
+  -> (t, string) result
+  = "rust_data_frame_import_stream_from_c"
+
+let import_stream_from_c ~stream =
+  import_stream_from_c ~stream:(Nativeint.to_int_exn stream)
+;;
+
+let import_stream_from_c_exn ~stream =
+  import_stream_from_c ~stream |> Utils.string_result_ok_exn
+;;
+
 external clear : t -> t = "rust_data_frame_clear"
 
 external describe
@@ -71,14 +885,35 @@ external lazy_ : t -> Lazy_frame.t = "rust_data_frame_lazy"
 
 let in_lazy t ~f = lazy_ t |> f |> Lazy_frame.collect
 let in_lazy_exn t ~f = lazy_ t |> f |> Lazy_frame.collect_exn
-let select t ~exprs = in_lazy t ~f:(Lazy_frame.select ~exprs)
-let select_exn t ~exprs = in_lazy_exn t ~f:(Lazy_frame.select ~exprs)
-let with_columns t ~exprs = in_lazy t ~f:(Lazy_frame.with_columns ~exprs)
-let with_columns_exn t ~exprs = in_lazy_exn t ~f:(Lazy_frame.with_columns ~exprs)
-let groupby ?is_stable t ~by ~agg = in_lazy t ~f:(Lazy_frame.groupby ?is_stable ~by ~agg)
+
+external select : t -> exprs:Expr.t list -> (t, string) result = "rust_data_frame_select"
+
+let select_exn t ~exprs = select t ~exprs |> Utils.string_result_ok_exn
+
+external with_columns
+  :  t
+  -> exprs:Expr.t list
+  -> (t, string) result
+  = "rust_data_frame_with_columns"
+
+let with_columns_exn t ~exprs = with_columns t ~exprs |> Utils.string_result_ok_exn
+
+external filter : t -> predicate:Expr.t -> (t, string) result = "rust_data_frame_filter"
+
+let filter_exn t ~predicate = filter t ~predicate |> Utils.string_result_ok_exn
+
+external groupby
+  :  t
+  -> is_stable:bool
+  -> by:Expr.t list
+  -> agg:Expr.t list
+  -> (t, string) result
+  = "rust_data_frame_groupby"
+
+let groupby ?(is_stable = true) t ~by ~agg = groupby t ~is_stable ~by ~agg
 
 let groupby_exn ?is_stable t ~by ~agg =
-  in_lazy_exn t ~f:(Lazy_frame.groupby ?is_stable ~by ~agg)
+  groupby ?is_stable t ~by ~agg |> Utils.string_result_ok_exn
 ;;
 
 let groupby_dynamic
@@ -155,6 +990,64 @@ external columns
 
 let columns_exn t ~names = columns t ~names |> Utils.string_result_ok_exn
 
+external get_any_value
+  :  t
+  -> name:string
+  -> int
+  -> (Any_value.t, string) result
+  = "rust_data_frame_get_any_value"
+
+let get_any_value_exn t ~name i = get_any_value t ~name i |> Utils.string_result_ok_exn
+
+external get_rows
+  :  t
+  -> offset:int
+  -> length:int
+  -> (Any_value.t list list, string) result
+  = "rust_data_frame_get_rows"
+
+let get_row t i =
+  get_rows t ~offset:i ~length:1
+  |> Result.map ~f:(fun rows -> Array.of_list (List.hd_exn rows))
+;;
+
+let get_row_exn t i = get_row t i |> Utils.string_result_ok_exn
+
+external get_typed_rows
+  :  t
+  -> 'a Row_type.t
+  -> offset:int
+  -> length:int
+  -> 'a list
+  = "rust_data_frame_get_typed_rows"
+
+let get_typed_rows t row_type ~offset ~length =
+  match Row_type.flatten_custom row_type with
+  | Flattened (row_type, f) -> get_typed_rows t row_type ~offset ~length |> List.map ~f
+;;
+
+let get_typed_row row_type t i =
+  get_typed_rows t row_type ~offset:i ~length:1 |> List.hd_exn
+;;
+
+let iter_chunks ?(chunk_size = 1000) t ~get_rows =
+  if chunk_size <= 0
+  then raise_s [%message "[chunk_size] must be positive" (chunk_size : int)];
+  Sequence.unfold ~init:0 ~f:(fun offset ->
+    let length = Int.min chunk_size (height t - offset) in
+    if length <= 0 then None else Some (get_rows ~offset ~length, offset + length))
+  |> Sequence.concat_map ~f:Sequence.of_list
+;;
+
+let iter_rows ?chunk_size t =
+  iter_chunks ?chunk_size t ~get_rows:(fun ~offset ~length ->
+    get_rows t ~offset ~length |> Utils.string_result_ok_exn |> List.map ~f:Array.of_list)
+;;
+
+let iter_typed_rows ?chunk_size row_type t =
+  iter_chunks ?chunk_size t ~get_rows:(get_typed_rows t row_type)
+;;
+
 external get_column_names : t -> string list = "rust_data_frame_get_column_names"
 
 external vertical_concat
@@ -342,6 +1235,70 @@ external explode
 
 let explode_exn t ~columns = explode t ~columns |> Utils.string_result_ok_exn
 
+external unique
+  :  t
+  -> subset:string list option
+  -> keep:[ `First | `Last | `None | `Any ]
+  -> maintain_order:bool
+  -> (t, string) result
+  = "rust_data_frame_unique"
+
+let unique ?subset ?(keep = `Any) ?(maintain_order = false) t =
+  unique t ~subset ~keep ~maintain_order
+;;
+
+let unique_exn ?subset ?keep ?maintain_order t =
+  unique ?subset ?keep ?maintain_order t |> Utils.string_result_ok_exn
+;;
+
+external drop_nulls
+  :  t
+  -> subset:string list option
+  -> (t, string) result
+  = "rust_data_frame_drop_nulls"
+
+let drop_nulls ?subset t = drop_nulls t ~subset
+let drop_nulls_exn ?subset t = drop_nulls ?subset t |> Utils.string_result_ok_exn
+
+external drop : t -> names:string list -> (t, string) result = "rust_data_frame_drop"
+
+let drop_exn t ~names = drop t ~names |> Utils.string_result_ok_exn
+
+external rename_many
+  :  t
+  -> mapping:(string * string) list
+  -> (t, string) result
+  = "rust_data_frame_rename"
+
+let rename_many_exn t ~mapping = rename_many t ~mapping |> Utils.string_result_ok_exn
+let rename t ~existing ~new_name = rename_many t ~mapping:[ existing, new_name ]
+
+let rename_exn t ~existing ~new_name =
+  rename t ~existing ~new_name |> Utils.string_result_ok_exn
+;;
+
+external replace_column
+  :  t
+  -> index:int
+  -> series:Series.t
+  -> (t, string) result
+  = "rust_data_frame_replace_column"
+
+let replace_column_exn t ~index ~series =
+  replace_column t ~index ~series |> Utils.string_result_ok_exn
+;;
+
+external insert_column
+  :  t
+  -> index:int
+  -> series:Series.t
+  -> (t, string) result
+  = "rust_data_frame_insert_column"
+
+let insert_column_exn t ~index ~series =
+  insert_column t ~index ~series |> Utils.string_result_ok_exn
+;;
+
 external schema : t -> Schema.t = "rust_data_frame_schema"
 external to_string_hum : t -> string = "rust_data_frame_to_string_hum"
 
@@ -2,24 +2,395 @@ open! Core
 
 type t = Data_frame0.t
 
+type in_memory =
+  [ `Bigstring of Bigstring.t
+  | `Bytes of bytes
+  | `String of string
+  ]
+
 val create : Series.t list -> (t, string) result
 val create_exn : Series.t list -> t
-val read_csv : ?schema:Schema.t -> ?try_parse_dates:bool -> string -> (t, string) result
-val read_csv_exn : ?schema:Schema.t -> ?try_parse_dates:bool -> string -> t
-val write_csv : t -> string -> (unit, string) result
-val write_csv_exn : t -> string -> unit
-val read_parquet : string -> (t, string) result
-val read_parquet_exn : string -> t
-val write_parquet : t -> string -> (unit, string) result
-val write_parquet_exn : t -> string -> unit
+
+(** Builds a data frame out of rows of values, converting each value to the data type of
+    its column and reporting the row and column of the first value that can't be.
+
+    Without a [schema], columns are named [column_0], [column_1], etc. and get the data
+    type of their first non-null value. This is much slower than building data frames
+    from series. *)
+val of_rows : ?schema:Schema.t -> Any_value.t list list -> (t, string) result
+
+val of_rows_exn : ?schema:Schema.t -> Any_value.t list list -> t
+
+val read_csv
+  :  ?schema:Schema.t
+  -> ?try_parse_dates:bool
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?columns:string list
+  -> ?projection:int list
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> string
+  -> (t, string) result
+
+val read_csv_exn
+  :  ?schema:Schema.t
+  -> ?try_parse_dates:bool
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?columns:string list
+  -> ?projection:int list
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> string
+  -> t
+
+val read_csv_from_memory
+  :  ?schema:Schema.t
+  -> ?try_parse_dates:bool
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?columns:string list
+  -> ?projection:int list
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> in_memory
+  -> (t, string) result
+
+val read_csv_from_memory_exn
+  :  ?schema:Schema.t
+  -> ?try_parse_dates:bool
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?columns:string list
+  -> ?projection:int list
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> in_memory
+  -> t
+
+val write_csv
+  :  ?has_header:bool
+  -> ?separator:char
+  -> ?quote_char:char
+  -> ?quote_style:[ `Always | `Necessary | `Non_numeric ]
+  -> ?null_value:string
+  -> ?line_terminator:string
+  -> ?float_precision:int
+  -> ?date_format:string
+  -> ?datetime_format:string
+  -> ?time_format:string
+  -> t
+  -> string
+  -> (unit, string) result
+
+val write_csv_exn
+  :  ?has_header:bool
+  -> ?separator:char
+  -> ?quote_char:char
+  -> ?quote_style:[ `Always | `Necessary | `Non_numeric ]
+  -> ?null_value:string
+  -> ?line_terminator:string
+  -> ?float_precision:int
+  -> ?date_format:string
+  -> ?datetime_format:string
+  -> ?time_format:string
+  -> t
+  -> string
+  -> unit
+
+val write_csv_to_string
+  :  ?has_header:bool
+  -> ?separator:char
+  -> ?quote_char:char
+  -> ?quote_style:[ `Always | `Necessary | `Non_numeric ]
+  -> ?null_value:string
+  -> ?line_terminator:string
+  -> ?float_precision:int
+  -> ?date_format:string
+  -> ?datetime_format:string
+  -> ?time_format:string
+  -> t
+  -> (string, string) result
+
+val write_csv_to_string_exn
+  :  ?has_header:bool
+  -> ?separator:char
+  -> ?quote_char:char
+  -> ?quote_style:[ `Always | `Necessary | `Non_numeric ]
+  -> ?null_value:string
+  -> ?line_terminator:string
+  -> ?float_precision:int
+  -> ?date_format:string
+  -> ?datetime_format:string
+  -> ?time_format:string
+  -> t
+  -> string
+
+val read_parquet
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> string
+  -> (t, string) result
+
+val read_parquet_exn
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> string
+  -> t
+
+val read_parquet_from_memory
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> in_memory
+  -> (t, string) result
+
+val read_parquet_from_memory_exn
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> in_memory
+  -> t
+
+(** Writes [t] to [path] as Parquet, returning the size of the written file in
+    bytes. *)
+val write_parquet
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> string
+  -> (int, string) result
+
+val write_parquet_exn
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> string
+  -> int
+
+val write_parquet_to_string
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> (string, string) result
+
+val write_parquet_to_string_exn
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> string
+
 val read_json : string -> (t, string) result
 val read_json_exn : string -> t
+val read_json_from_memory : in_memory -> (t, string) result
+val read_json_from_memory_exn : in_memory -> t
 val write_json : t -> string -> (unit, string) result
 val write_json_exn : t -> string -> unit
+val write_json_to_string : t -> (string, string) result
+val write_json_to_string_exn : t -> string
 val read_jsonl : string -> (t, string) result
 val read_jsonl_exn : string -> t
+val read_jsonl_from_memory : in_memory -> (t, string) result
+val read_jsonl_from_memory_exn : in_memory -> t
 val write_jsonl : t -> string -> (unit, string) result
 val write_jsonl_exn : t -> string -> unit
+val write_jsonl_to_string : t -> (string, string) result
+val write_jsonl_to_string_exn : t -> string
+
+val read_ipc
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?memory_map:bool
+  -> ?rechunk:bool
+  -> string
+  -> (t, string) result
+
+val read_ipc_exn
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?memory_map:bool
+  -> ?rechunk:bool
+  -> string
+  -> t
+
+val read_ipc_from_memory
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?rechunk:bool
+  -> in_memory
+  -> (t, string) result
+
+val read_ipc_from_memory_exn
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?rechunk:bool
+  -> in_memory
+  -> t
+
+val write_ipc : ?compression:[ `Lz4 | `Zstd ] -> t -> string -> (unit, string) result
+val write_ipc_exn : ?compression:[ `Lz4 | `Zstd ] -> t -> string -> unit
+val write_ipc_to_string : ?compression:[ `Lz4 | `Zstd ] -> t -> (string, string) result
+val write_ipc_to_string_exn : ?compression:[ `Lz4 | `Zstd ] -> t -> string
+
+val write_ipc_stream
+  :  ?compression:[ `Lz4 | `Zstd ]
+  -> t
+  -> Core_unix.File_descr.t
+  -> (unit, string) result
+
+val write_ipc_stream_exn
+  :  ?compression:[ `Lz4 | `Zstd ]
+  -> t
+  -> Core_unix.File_descr.t
+  -> unit
+
+(** Reads a single Arrow IPC stream from the file descriptor, returning [None]
+    if it is already at EOF. Each call consumes exactly one stream, so frames
+    written one after another with [write_ipc_stream] can be read back one at a
+    time. [n_rows] only limits the rows returned: the rest of the stream is still
+    read and discarded. *)
+val read_ipc_stream
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?rechunk:bool
+  -> Core_unix.File_descr.t
+  -> (t option, string) result
+
+val read_ipc_stream_exn
+  :  ?columns:string list
+  -> ?projection:int list
+  -> ?n_rows:int
+  -> ?row_count:string * int
+  -> ?rechunk:bool
+  -> Core_unix.File_descr.t
+  -> t option
+
+(** Writes one file per distinct combination of values of the [partition_by] columns
+    to [root/key=value/.../part-<n>.<format>], the layout read by
+    [Lazy_frame.scan_parquet ~hive_partitioning:true]. [n] is the first number not yet
+    used in the directory, so writing to the same [root] again adds files next to the
+    existing ones. The partition columns are left out of the files themselves.
+    [compression], [statistics], [row_group_size] and [data_page_size] apply to Parquet
+    files as in [write_parquet], and are ignored for other formats. Returns the path
+    and number of rows of each file written. *)
+val write_partitioned
+  :  ?format:[ `Csv | `Ipc | `Parquet ]
+  -> ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> partition_by:string list
+  -> string
+  -> ((string * int) list, string) result
+
+val write_partitioned_exn
+  :  ?format:[ `Csv | `Ipc | `Parquet ]
+  -> ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> partition_by:string list
+  -> string
+  -> (string * int) list
+
+(** Exports the data frame through the Arrow C Data Interface as a single struct array
+    with one field per column, written to the caller-allocated [ArrowSchema] and
+    [ArrowArray] structs at the given addresses. The caller then owns the exported
+    structs and must call their [release] callbacks. Fails on frames without columns. *)
+val export_to_c : t -> schema:nativeint -> array:nativeint -> (unit, string) result
+
+val export_to_c_exn : t -> schema:nativeint -> array:nativeint -> unit
+
+(** Imports a data frame from a struct array exported through the Arrow C Data
+    Interface. Null struct entries become rows of nulls. The structs are marked as
+    released and must not be released again by the caller. *)
+val import_from_c : schema:nativeint -> array:nativeint -> (t, string) result
+
+val import_from_c_exn : schema:nativeint -> array:nativeint -> t
+
+(** Like [export_to_c], but writes an [ArrowArrayStream] yielding one struct array per
+    chunk of the data frame, avoiding a copy into a single chunk. *)
+val export_stream_to_c : t -> stream:nativeint -> (unit, string) result
+
+val export_stream_to_c_exn : t -> stream:nativeint -> unit
+
+(** Imports a data frame from an [ArrowArrayStream] of struct arrays, one chunk per
+    array. The stream is consumed and released. *)
+val import_stream_from_c : stream:nativeint -> (t, string) result
+
+val import_stream_from_c_exn : stream:nativeint -> t
+
 val clear : t -> t
 val describe_exn : ?percentiles:float list -> t -> t
 val height : t -> int
@@ -28,6 +399,8 @@ val select : t -> exprs:Expr.t list -> (t, string) result
 val select_exn : t -> exprs:Expr.t list -> t
 val with_columns : t -> exprs:Expr.t list -> (t, string) result
 val with_columns_exn : t -> exprs:Expr.t list -> t
+val filter : t -> predicate:Expr.t -> (t, string) result
+val filter_exn : t -> predicate:Expr.t -> t
 
 val groupby
   :  ?is_stable:bool
@@ -92,6 +465,29 @@ val column : t -> name:string -> (Series.t, string) result
 val column_exn : t -> name:string -> Series.t
 val columns : t -> names:string list -> (Series.t list, string) result
 val columns_exn : t -> names:string list -> Series.t list
+
+(** Gets the value in the given column and row, see [Series.get_any_value]. *)
+val get_any_value : t -> name:string -> int -> (Any_value.t, string) result
+
+val get_any_value_exn : t -> name:string -> int -> Any_value.t
+
+(** Gets the values of every column in the given row. *)
+val get_row : t -> int -> (Any_value.t array, string) result
+
+val get_row_exn : t -> int -> Any_value.t array
+
+(** Gets the given row as nested pairs, e.g.
+    [get_typed_row [ Int64; Utf8 ] t 0 : int option * (string option * unit)]. Raises
+    if the row type doesn't have one data type per column matching the column's. *)
+val get_typed_row : 'a Row_type.t -> t -> int -> 'a
+
+(** Iterates over the rows of the data frame, converting [chunk_size] rows (1000 by
+    default) at a time. The rows are read as the sequence is consumed, so the data frame
+    shouldn't be modified (e.g. by [vstack]) in the meantime. *)
+val iter_rows : ?chunk_size:int -> t -> Any_value.t array Sequence.t
+
+(** Like [iter_rows], but reads rows as [get_typed_row] does. *)
+val iter_typed_rows : ?chunk_size:int -> 'a Row_type.t -> t -> 'a Sequence.t
 val get_column_names : t -> string list
 val concat : ?how:[ `Diagonal | `Horizontal | `Vertical ] -> t list -> (t, string) result
 val concat_exn : ?how:[ `Diagonal | `Horizontal | `Vertical ] -> t list -> t
@@ -214,6 +610,52 @@ val upsample_exn
 
 val explode : t -> columns:string list -> (t, string) result
 val explode_exn : t -> columns:string list -> t
+
+(** Keeps one row out of each group of rows with the same values in [subset] (all
+    columns by default). [keep] picks which row of each group is kept: [`None] drops
+    every row that has a duplicate, and [`Any] (the default) is the fastest. The
+    order of the rows is unspecified unless [maintain_order] is [true], which keeps
+    them in their original order at some extra cost. *)
+val unique
+  :  ?subset:string list
+  -> ?keep:[ `First | `Last | `None | `Any ]
+  -> ?maintain_order:bool
+  -> t
+  -> (t, string) result
+
+val unique_exn
+  :  ?subset:string list
+  -> ?keep:[ `First | `Last | `None | `Any ]
+  -> ?maintain_order:bool
+  -> t
+  -> t
+
+(** Drops rows with a null in any of the columns in [subset] (all columns by
+    default). *)
+val drop_nulls : ?subset:string list -> t -> (t, string) result
+
+val drop_nulls_exn : ?subset:string list -> t -> t
+val drop : t -> names:string list -> (t, string) result
+val drop_exn : t -> names:string list -> t
+val rename : t -> existing:string -> new_name:string -> (t, string) result
+val rename_exn : t -> existing:string -> new_name:string -> t
+
+(** Renames each column in the first component of [mapping] to the second. Columns are
+    renamed all at once, so that two columns can swap their names. *)
+val rename_many : t -> mapping:(string * string) list -> (t, string) result
+
+val rename_many_exn : t -> mapping:(string * string) list -> t
+
+(** Replaces the column at [index] with [series], which keeps its own name. This fails
+    if another column already has that name. *)
+val replace_column : t -> index:int -> series:Series.t -> (t, string) result
+
+val replace_column_exn : t -> index:int -> series:Series.t -> t
+
+(** Inserts [series] as a new column at [index], shifting the columns after it. *)
+val insert_column : t -> index:int -> series:Series.t -> (t, string) result
+
+val insert_column_exn : t -> index:int -> series:Series.t -> t
 val schema : t -> Schema.t
 val to_string_hum : t -> string
 val print : t -> unit
@@ -39,6 +39,9 @@ module T = struct
     [@quickcheck.weight 10.]
     | Null
     | Struct of (string * t) list
+    | Categorical
+    | Decimal of int option * int option
+    | Array of t * int
     | Unknown
   [@@deriving compare, sexp, quickcheck]
 end
@@ -65,7 +68,14 @@ module Typed = struct
     | Float64 : float t
     | Utf8 : string t
     | Binary : string t
+    | Date : Common.Naive_date.t t
+    | Datetime : Time_unit.t * string option -> Common.Naive_datetime.t t
+    | Duration : Time_unit.t -> Time_ns.Span.t t
+    | Time : Time_ns.Ofday.t t
     | List : 'a t -> 'a list t
+    | Struct : 'a fields -> 'a t
+    | Decimal : int option * int -> int t
+    | Array : 'a t * int -> 'a list t
     | Custom :
         { data_type : 'a t
         ; f : 'a -> 'b
@@ -73,6 +83,10 @@ module Typed = struct
         }
         -> 'b t
 
+  and _ fields =
+    | Empty : unit fields
+    | Field : string * 'a t * 'b fields -> ('a * 'b) fields
+
   let rec strict_type_equal : type a b. a t -> b t -> (a, b) Type_equal.t option =
     fun t1 t2 ->
     match t1, t2 with
@@ -89,20 +103,68 @@ module Typed = struct
     | Float64, Float64 -> Some Type_equal.T
     | Utf8, Utf8 -> Some Type_equal.T
     | Binary, Binary -> Some Type_equal.T
+    | Date, Date -> Some Type_equal.T
+    | Datetime (time_unit1, time_zone1), Datetime (time_unit2, time_zone2) ->
+      if [%compare.equal: Time_unit.t * string option]
+           (time_unit1, time_zone1)
+           (time_unit2, time_zone2)
+      then Some Type_equal.T
+      else None
+    | Duration time_unit1, Duration time_unit2 ->
+      if [%compare.equal: Time_unit.t] time_unit1 time_unit2
+      then Some Type_equal.T
+      else None
+    | Time, Time -> Some Type_equal.T
     | List t1, List t2 ->
       (match strict_type_equal t1 t2 with
        | None -> None
        | Some Type_equal.T -> Some Type_equal.T)
+    | Struct fields1, Struct fields2 ->
+      (match strict_fields_equal fields1 fields2 with
+       | None -> None
+       | Some Type_equal.T -> Some Type_equal.T)
+    | Decimal (precision1, scale1), Decimal (precision2, scale2) ->
+      if [%compare.equal: int option * int] (precision1, scale1) (precision2, scale2)
+      then Some Type_equal.T
+      else None
+    | Array (t1, width1), Array (t2, width2) ->
+      (match strict_type_equal t1 t2 with
+       | Some Type_equal.T when width1 = width2 -> Some Type_equal.T
+       | _ -> None)
+    | _, _ -> None
+
+  and strict_fields_equal : type a b. a fields -> b fields -> (a, b) Type_equal.t option =
+    fun fields1 fields2 ->
+    match fields1, fields2 with
+    | Empty, Empty -> Some Type_equal.T
+    | Field (name1, t1, rest1), Field (name2, t2, rest2) ->
+      if String.equal name1 name2
+      then (
+        match strict_type_equal t1 t2, strict_fields_equal rest1 rest2 with
+        | Some Type_equal.T, Some Type_equal.T -> Some Type_equal.T
+        | _, _ -> None)
+      else None
     | _, _ -> None
   ;;
 
+  (* The result of extracting any [Custom _] out of the fields of a struct, which is
+     [Unchanged] if none of the fields contain one. *)
+  type 'a flattened_fields =
+    | Unchanged : 'a flattened_fields
+    | Mapped :
+        { fields : 'b fields
+        ; f : 'b -> 'a
+        ; f_inverse : 'a -> 'b
+        }
+        -> 'a flattened_fields
+
   let rec flatten_custom : type a. a t -> a t = function
-    | Custom
-        { data_type = Custom { data_type; f = f'; f_inverse = f_inverse' }; f; f_inverse }
-      ->
-      flatten_custom
-        (Custom
-           { data_type; f = Fn.compose f f'; f_inverse = Fn.compose f_inverse' f_inverse })
+    | Custom { data_type; f; f_inverse } ->
+      (match flatten_custom data_type with
+       | Custom { data_type; f = f'; f_inverse = f_inverse' } ->
+         Custom
+           { data_type; f = Fn.compose f f'; f_inverse = Fn.compose f_inverse' f_inverse }
+       | data_type -> Custom { data_type; f; f_inverse })
     | List t ->
       (match flatten_custom t with
        | Custom { data_type; f; f_inverse } ->
@@ -112,7 +174,46 @@ module Typed = struct
            ; f_inverse = List.map ~f:f_inverse
            }
        | t -> List t)
+    | Array (t, width) ->
+      (match flatten_custom t with
+       | Custom { data_type; f; f_inverse } ->
+         Custom
+           { data_type = Array (data_type, width)
+           ; f = List.map ~f
+           ; f_inverse = List.map ~f:f_inverse
+           }
+       | t -> Array (t, width))
+    | Struct fields ->
+      (match flatten_custom_fields fields with
+       | Unchanged -> Struct fields
+       | Mapped { fields; f; f_inverse } ->
+         Custom { data_type = Struct fields; f; f_inverse })
     | t -> t
+
+  and flatten_custom_fields : type a. a fields -> a flattened_fields = function
+    | Empty -> Unchanged
+    | Field (name, data_type, rest) ->
+      (match flatten_custom data_type, flatten_custom_fields rest with
+       | Custom { data_type; f; f_inverse }, Unchanged ->
+         Mapped
+           { fields = Field (name, data_type, rest)
+           ; f = (fun (value, rest) -> f value, rest)
+           ; f_inverse = (fun (value, rest) -> f_inverse value, rest)
+           }
+       | ( Custom { data_type; f; f_inverse }
+         , Mapped { fields; f = f'; f_inverse = f_inverse' } ) ->
+         Mapped
+           { fields = Field (name, data_type, fields)
+           ; f = (fun (value, rest) -> f value, f' rest)
+           ; f_inverse = (fun (value, rest) -> f_inverse value, f_inverse' rest)
+           }
+       | data_type, Mapped { fields; f; f_inverse } ->
+         Mapped
+           { fields = Field (name, data_type, fields)
+           ; f = (fun (value, rest) -> value, f rest)
+           ; f_inverse = (fun (value, rest) -> value, f_inverse rest)
+           }
+       | _, Unchanged -> Unchanged)
   ;;
 
   type packed = T : _ t -> packed
@@ -131,10 +232,23 @@ module Typed = struct
     | Float64 -> Float64
     | Utf8 -> Utf8
     | Binary -> Binary
+    | Date -> Date
+    | Datetime (time_unit, time_zone) -> Datetime (time_unit, time_zone)
+    | Duration time_unit -> Duration time_unit
+    | Time -> Time
     | List t -> List (to_untyped t)
+    | Struct fields -> Struct (fields_to_untyped fields)
+    | Decimal (precision, scale) -> Decimal (precision, Some scale)
+    | Array (t, width) -> Array (to_untyped t, width)
     | Custom { data_type; f = _; f_inverse = _ } -> to_untyped data_type
+
+  and fields_to_untyped : type a. a fields -> (string * untyped) list = function
+    | Empty -> []
+    | Field (name, t, rest) -> (name, to_untyped t) :: fields_to_untyped rest
   ;;
 
+  type packed_fields = Fields : _ fields -> packed_fields
+
   let rec of_untyped : untyped -> packed option = function
     | Boolean -> Some (T Boolean)
     | UInt8 -> Some (T UInt8)
@@ -149,8 +263,24 @@ module Typed = struct
     | Float64 -> Some (T Float64)
     | Utf8 -> Some (T Utf8)
     | Binary -> Some (T Binary)
+    | Date -> Some (T Date)
+    | Datetime (time_unit, time_zone) -> Some (T (Datetime (time_unit, time_zone)))
+    | Duration time_unit -> Some (T (Duration time_unit))
+    | Time -> Some (T Time)
     | List t -> of_untyped t |> Option.map ~f:(fun (T t) -> T (List t))
-    | Date | Datetime _ | Duration _ | Time | Null | Struct _ | Unknown -> None
+    | Struct fields ->
+      of_untyped_fields fields |> Option.map ~f:(fun (Fields fields) -> T (Struct fields))
+    | Decimal (precision, Some scale) -> Some (T (Decimal (precision, scale)))
+    | Array (t, width) ->
+      of_untyped t |> Option.map ~f:(fun (T t) -> T (Array (t, width)))
+    | Decimal (_, None) | Null | Categorical | Unknown -> None
+
+  and of_untyped_fields : (string * untyped) list -> packed_fields option = function
+    | [] -> Some (Fields Empty)
+    | (name, t) :: rest ->
+      (match of_untyped t, of_untyped_fields rest with
+       | Some (T t), Some (Fields rest) -> Some (Fields (Field (name, t, rest)))
+       | _, _ -> None)
   ;;
 
   let rec sexp_of_packed (T t) =
@@ -175,9 +305,43 @@ module Typed = struct
       T (Custom { data_type; f = Fn.id; f_inverse = Fn.id })
   ;;
 
+  let rec is_valid_list_element : untyped -> bool = function
+    | Decimal _ | Array _ -> false
+    | List t -> is_valid_list_element t
+    | Struct fields -> List.for_all fields ~f:(fun (_name, t) -> is_valid_list_element t)
+    | _ -> true
+  ;;
+
+  (* Not every generated data type can be used to create a series: arbitrary strings
+     are almost never valid time zones, so we only generate datetimes which are either
+     naive or in UTC, and struct fields need distinct names and at least one field.
+     Decimal values are OCaml ints, so precisions and scales are limited to 18 digits,
+     and Polars can't build lists of decimals or arrays. Arrays need a positive width
+     and are only supported for non-nested data types. *)
+  let rec valid_for_series : untyped -> untyped option = function
+    | Datetime (time_unit, Some _) -> Some (Datetime (time_unit, Some "UTC"))
+    | Decimal (precision, Some scale) ->
+      let precision = Option.map precision ~f:(fun precision -> 1 + (precision % 18)) in
+      Some (Decimal (precision, Some (scale % 19)))
+    | List t when not (is_valid_list_element t) -> None
+    | List t -> valid_for_series t |> Option.map ~f:(fun t -> (List t : untyped))
+    | Array ((List _ | Struct _ | Decimal _ | Array _), _) -> None
+    | Array (t, width) ->
+      valid_for_series t
+      |> Option.map ~f:(fun t -> (Array (t, 1 + (width % 8)) : untyped))
+    | Struct [] -> None
+    | Struct fields ->
+      List.mapi fields ~f:(fun i (_name, t) ->
+        valid_for_series t |> Option.map ~f:(fun t -> sprintf "field%d" i, t))
+      |> Option.all
+      |> Option.map ~f:(fun fields -> (Struct fields : untyped))
+    | t -> Some t
+  ;;
+
   let quickcheck_generator_packed =
     let generator_without_custom =
-      Quickcheck.Generator.filter_map quickcheck_generator ~f:of_untyped
+      Quickcheck.Generator.filter_map quickcheck_generator ~f:(fun t ->
+        valid_for_series t |> Option.bind ~f:of_untyped)
     in
     quickcheck_generator_wrapped generator_without_custom
     |> Quickcheck.Generator.map ~f:unwrap
@@ -186,7 +350,7 @@ module Typed = struct
   let quickcheck_shrinker_packed =
     Quickcheck.Shrinker.filter_map
       quickcheck_shrinker
-      ~f:of_untyped
+      ~f:(fun t -> valid_for_series t |> Option.bind ~f:of_untyped)
       ~f_inverse:(fun (T t) -> to_untyped t)
   ;;
 
@@ -29,6 +29,13 @@ type t =
   | List of t
   | Null
   | Struct of (string * t) list
+  | Categorical
+  | Decimal of int option * int option
+  (** Precision (the maximum number of digits, if any) and scale (the number of
+      digits after the decimal point). The scale may only be omitted when Polars can
+      infer it. *)
+  | Array of t * int
+  (** Inner data type and width: every value holds exactly [width] elements. *)
   | Unknown
 [@@deriving compare, sexp, quickcheck]
 
@@ -51,7 +58,26 @@ module Typed : sig
       | Float64 : float t
       | Utf8 : string t
       | Binary : string t
+      | Date : Common.Naive_date.t t
+      | Datetime : Time_unit.t * string option -> Common.Naive_datetime.t t
+      (** Values are truncated to the precision of the time unit, and are in UTC if
+          there is a time zone. *)
+      | Duration : Time_unit.t -> Time_ns.Span.t t
+      (** Spans are truncated to the precision of the time unit. *)
+      | Time : Time_ns.Ofday.t t
       | List : 'a t -> 'a list t
+      | Struct : 'a fields -> 'a t
+      (** Values of structs are nested pairs with one component per field, e.g. values
+          of [Struct (Field ("a", Int64, Field ("b", Utf8, Empty)))] have type
+          [int * (string * unit)]. Use [Custom] to convert them to records. Null values
+          are stored as rows in which every field is null. *)
+      | Decimal : int option * int -> int t
+      (** Values are scaled by [10 ** scale], e.g. 12.34 is [1234] in
+          [Decimal (None, 2)], so they are exact but limited to the range of OCaml
+          ints. *)
+      | Array : 'a t * int -> 'a list t
+      (** Values are lists with exactly [width] elements, e.g. [Array (Float32, 128)]
+          for 128-dimensional embeddings. *)
       | Custom :
           { data_type : 'a t
           ; f : 'a -> 'b
@@ -59,6 +85,10 @@ module Typed : sig
           }
           -> 'b t
 
+    and _ fields =
+      | Empty : unit fields
+      | Field : string * 'a t * 'b fields -> ('a * 'b) fields
+
     (** [strict_type_equal] returns type equality only if the two arguments
         correspond to the same exact branch. *)
     val strict_type_equal : 'a t -> 'b t -> ('a, 'b) Type_equal.t option
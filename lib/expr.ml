@@ -86,6 +86,7 @@ module T = struct
   external pow : t -> t -> t = "rust_expr_pow"
   external sum : t -> t = "rust_expr_sum"
   external mean : t -> t = "rust_expr_mean"
+  external decimal_mean : t -> t = "rust_expr_decimal_mean"
   external median : t -> t = "rust_expr_median"
   external mode : t -> t = "rust_expr_mode"
   external max : t -> t = "rust_expr_max"
@@ -309,3 +310,14 @@ module List = struct
 
   let eval ?(parallel = false) t ~expr = eval t ~expr ~parallel
 end
+
+module Arr = struct
+  external sum : t -> t = "rust_expr_arr_sum"
+  external min : t -> t = "rust_expr_arr_min"
+  external max : t -> t = "rust_expr_arr_max"
+  external unique : t -> maintain_order:bool -> t = "rust_expr_arr_unique"
+
+  let unique ?(maintain_order = false) t = unique t ~maintain_order
+
+  external to_list : t -> t = "rust_expr_arr_to_list"
+end
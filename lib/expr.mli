@@ -844,7 +844,15 @@ val pow : t -> t -> t
     ]} *)
 val sum : t -> t
 
+(** [mean] is always null on Decimal columns, as Polars doesn't implement it for them;
+    use [decimal_mean] instead. *)
 val mean : t -> t
+
+(** [decimal_mean] is [mean] for Decimal columns. The result is a Decimal computed from
+    the exact sum, with 4 more digits of precision and scale than the input, rounded
+    half away from zero (so the mean of 1 and 2 with scale 0 is 1.5000). *)
+val decimal_mean : t -> t
+
 val median : t -> t
 val mode : t -> t
 
@@ -1034,3 +1042,17 @@ module List : sig
   val sum : t -> t
   val eval : ?parallel:bool -> t -> expr:t -> t
 end
+
+(** Expressions on columns of fixed-size [Array] data types. *)
+module Arr : sig
+  val sum : t -> t
+  val min : t -> t
+  val max : t -> t
+
+  (** Each value becomes a list of its distinct elements, since they may have
+      different numbers of them. *)
+  val unique : ?maintain_order:bool -> t -> t
+
+  (** Converts values to lists with the same elements. *)
+  val to_list : t -> t
+end
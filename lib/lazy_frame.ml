@@ -2,13 +2,328 @@ open! Core
 
 type t
 
-external scan_parquet : string -> (t, string) result = "rust_lazy_frame_scan_parquet"
+external scan_parquet
+  :  string list
+  -> n_rows:int option
+  -> row_count:(string * int) option
+  -> parallel:[ `Auto | `Columns | `None | `Row_groups ] option
+  -> low_memory:bool option
+  -> rechunk:bool option
+  -> cache:bool option
+  -> hive_partitioning:bool option
+  -> hive_schema:Schema.t option
+  -> (t, string) result
+  = "rust_lazy_frame_scan_parquet_bytecode" "rust_lazy_frame_scan_parquet"
+
+let scan_parquet_paths
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  paths
+  =
+  scan_parquet
+    paths
+    ~n_rows
+    ~row_count
+    ~parallel
+    ~low_memory
+    ~rechunk
+    ~cache
+    ~hive_partitioning
+    ~hive_schema
+;;
+
+let scan_parquet_paths_exn
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  paths
+  =
+  scan_parquet_paths
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    ?cache
+    ?hive_partitioning
+    ?hive_schema
+    paths
+  |> Utils.string_result_ok_exn
+;;
+
+let scan_parquet
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  path
+  =
+  scan_parquet_paths
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    ?cache
+    ?hive_partitioning
+    ?hive_schema
+    [ path ]
+;;
+
+let scan_parquet_exn
+  ?n_rows
+  ?row_count
+  ?parallel
+  ?low_memory
+  ?rechunk
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  path
+  =
+  scan_parquet
+    ?n_rows
+    ?row_count
+    ?parallel
+    ?low_memory
+    ?rechunk
+    ?cache
+    ?hive_partitioning
+    ?hive_schema
+    path
+  |> Utils.string_result_ok_exn
+;;
 
-let scan_parquet_exn path = scan_parquet path |> Utils.string_result_ok_exn
+external scan_csv
+  :  string list
+  -> schema:Schema.t option
+  -> dtypes:Schema.t option
+  -> separator:int option
+  -> has_header:bool option
+  -> skip_rows:int option
+  -> comment_char:int option
+  -> quote_char:int option
+  -> null_values:Null_values.t option
+  -> n_rows:int option
+  -> infer_schema_length:int option
+  -> ignore_errors:bool option
+  -> try_parse_dates:bool option
+  -> row_count:(string * int) option
+  -> encoding:[ `Utf8 | `Lossy_utf8 ] option
+  -> low_memory:bool option
+  -> cache:bool option
+  -> hive_partitioning:bool option
+  -> hive_schema:Schema.t option
+  -> (t, string) result
+  = "rust_lazy_frame_scan_csv_bytecode" "rust_lazy_frame_scan_csv"
+
+let scan_csv_paths
+  ?schema
+  ?dtypes
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?infer_schema_length
+  ?ignore_errors
+  ?try_parse_dates
+  ?row_count
+  ?encoding
+  ?low_memory
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  paths
+  =
+  scan_csv
+    paths
+    ~schema
+    ~dtypes
+    ~separator:(Option.map separator ~f:Char.to_int)
+    ~has_header
+    ~skip_rows
+    ~comment_char:(Option.map comment_char ~f:Char.to_int)
+    ~quote_char:(Option.map quote_char ~f:Char.to_int)
+    ~null_values
+    ~n_rows
+    ~infer_schema_length
+    ~ignore_errors
+    ~try_parse_dates
+    ~row_count
+    ~encoding
+    ~low_memory
+    ~cache
+    ~hive_partitioning
+    ~hive_schema
+;;
 
-external scan_csv : string -> (t, string) result = "rust_lazy_frame_scan_csv"
+let scan_csv_paths_exn
+  ?schema
+  ?dtypes
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?infer_schema_length
+  ?ignore_errors
+  ?try_parse_dates
+  ?row_count
+  ?encoding
+  ?low_memory
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  paths
+  =
+  scan_csv_paths
+    ?schema
+    ?dtypes
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?infer_schema_length
+    ?ignore_errors
+    ?try_parse_dates
+    ?row_count
+    ?encoding
+    ?low_memory
+    ?cache
+    ?hive_partitioning
+    ?hive_schema
+    paths
+  |> Utils.string_result_ok_exn
+;;
 
-let scan_csv_exn path = scan_csv path |> Utils.string_result_ok_exn
+let scan_csv
+  ?schema
+  ?dtypes
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?infer_schema_length
+  ?ignore_errors
+  ?try_parse_dates
+  ?row_count
+  ?encoding
+  ?low_memory
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  path
+  =
+  scan_csv_paths
+    ?schema
+    ?dtypes
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?infer_schema_length
+    ?ignore_errors
+    ?try_parse_dates
+    ?row_count
+    ?encoding
+    ?low_memory
+    ?cache
+    ?hive_partitioning
+    ?hive_schema
+    [ path ]
+;;
+
+let scan_csv_exn
+  ?schema
+  ?dtypes
+  ?separator
+  ?has_header
+  ?skip_rows
+  ?comment_char
+  ?quote_char
+  ?null_values
+  ?n_rows
+  ?infer_schema_length
+  ?ignore_errors
+  ?try_parse_dates
+  ?row_count
+  ?encoding
+  ?low_memory
+  ?cache
+  ?hive_partitioning
+  ?hive_schema
+  path
+  =
+  scan_csv
+    ?schema
+    ?dtypes
+    ?separator
+    ?has_header
+    ?skip_rows
+    ?comment_char
+    ?quote_char
+    ?null_values
+    ?n_rows
+    ?infer_schema_length
+    ?ignore_errors
+    ?try_parse_dates
+    ?row_count
+    ?encoding
+    ?low_memory
+    ?cache
+    ?hive_partitioning
+    ?hive_schema
+    path
+  |> Utils.string_result_ok_exn
+;;
+
+external scan_ipc
+  :  string
+  -> n_rows:int option
+  -> row_count:(string * int) option
+  -> memory_map:bool option
+  -> rechunk:bool option
+  -> cache:bool option
+  -> (t, string) result
+  = "rust_lazy_frame_scan_ipc_bytecode" "rust_lazy_frame_scan_ipc"
+
+let scan_ipc ?n_rows ?row_count ?memory_map ?rechunk ?cache path =
+  scan_ipc path ~n_rows ~row_count ~memory_map ~rechunk ~cache
+;;
+
+let scan_ipc_exn ?n_rows ?row_count ?memory_map ?rechunk ?cache path =
+  scan_ipc ?n_rows ?row_count ?memory_map ?rechunk ?cache path
+  |> Utils.string_result_ok_exn
+;;
 
 external scan_jsonl : string -> (t, string) result = "rust_lazy_frame_scan_jsonl"
 
@@ -75,6 +390,127 @@ external fetch
 
 let fetch_exn t ~n_rows = fetch t ~n_rows |> Utils.string_result_ok_exn
 
+external sink_parquet
+  :  t
+  -> string
+  -> compression:Parquet_compression.t
+  -> statistics:bool
+  -> row_group_size:int option
+  -> data_page_size:int option
+  -> maintain_order:bool
+  -> (unit, string) result
+  = "rust_lazy_frame_sink_parquet_bytecode" "rust_lazy_frame_sink_parquet"
+
+let sink_parquet
+  ?(compression = Parquet_compression.Zstd None)
+  ?(statistics = false)
+  ?row_group_size
+  ?data_page_size
+  ?(maintain_order = true)
+  t
+  path
+  =
+  sink_parquet
+    t
+    path
+    ~compression
+    ~statistics
+    ~row_group_size
+    ~data_page_size
+    ~maintain_order
+;;
+
+let sink_parquet_exn
+  ?compression
+  ?statistics
+  ?row_group_size
+  ?data_page_size
+  ?maintain_order
+  t
+  path
+  =
+  sink_parquet
+    ?compression
+    ?statistics
+    ?row_group_size
+    ?data_page_size
+    ?maintain_order
+    t
+    path
+  |> Utils.string_result_ok_exn
+;;
+
+external sink_ipc
+  :  t
+  -> string
+  -> compression:[ `Lz4 | `Zstd ] option
+  -> maintain_order:bool
+  -> (unit, string) result
+  = "rust_lazy_frame_sink_ipc"
+
+let sink_ipc ?compression ?(maintain_order = true) t path =
+  sink_ipc t path ~compression ~maintain_order
+;;
+
+let sink_ipc_exn ?compression ?maintain_order t path =
+  sink_ipc ?compression ?maintain_order t path |> Utils.string_result_ok_exn
+;;
+
+external write_partitioned
+  :  t
+  -> string
+  -> partition_by:string list
+  -> format:[ `Csv | `Ipc | `Parquet ]
+  -> compression:Parquet_compression.t
+  -> statistics:bool
+  -> row_group_size:int option
+  -> data_page_size:int option
+  -> ((string * int) list, string) result
+  = "rust_lazy_frame_write_partitioned_bytecode" "rust_lazy_frame_write_partitioned"
+
+let write_partitioned
+  ?(format = `Parquet)
+  ?(compression = Parquet_compression.Zstd None)
+  ?(statistics = false)
+  ?row_group_size
+  ?data_page_size
+  t
+  ~partition_by
+  root
+  =
+  write_partitioned
+    t
+    root
+    ~partition_by
+    ~format
+    ~compression
+    ~statistics
+    ~row_group_size
+    ~data_page_size
+;;
+
+let write_partitioned_exn
+  ?format
+  ?compression
+  ?statistics
+  ?row_group_size
+  ?data_page_size
+  t
+  ~partition_by
+  root
+  =
+  write_partitioned
+    ?format
+    ?compression
+    ?statistics
+    ?row_group_size
+    ?data_page_size
+    t
+    ~partition_by
+    root
+  |> Utils.string_result_ok_exn
+;;
+
 external filter : t -> predicate:Expr.t -> t = "rust_lazy_frame_filter"
 external select : t -> exprs:Expr.t list -> t = "rust_lazy_frame_select"
 external with_columns : t -> exprs:Expr.t list -> t = "rust_lazy_frame_with_columns"
@@ -227,6 +663,50 @@ let sort ?descending ?nulls_last t ~by_column =
 
 external limit : t -> n:int -> t = "rust_lazy_frame_limit"
 external explode : t -> columns:Expr.t list -> t = "rust_lazy_frame_explode"
+
+external unique
+  :  t
+  -> subset:string list option
+  -> keep:[ `First | `Last | `None | `Any ]
+  -> maintain_order:bool
+  -> t
+  = "rust_lazy_frame_unique"
+
+let unique ?subset ?(keep = `Any) ?(maintain_order = false) t =
+  unique t ~subset ~keep ~maintain_order
+;;
+
+external drop_nulls : t -> subset:string list option -> t = "rust_lazy_frame_drop_nulls"
+
+let drop_nulls ?subset t = drop_nulls t ~subset
+
+external drop : t -> names:string list -> t = "rust_lazy_frame_drop"
+external rename_many : t -> mapping:(string * string) list -> t = "rust_lazy_frame_rename"
+
+let rename t ~existing ~new_name = rename_many t ~mapping:[ existing, new_name ]
+
+external replace_column
+  :  t
+  -> index:int
+  -> expr:Expr.t
+  -> (t, string) result
+  = "rust_lazy_frame_replace_column"
+
+let replace_column_exn t ~index ~expr =
+  replace_column t ~index ~expr |> Utils.string_result_ok_exn
+;;
+
+external insert_column
+  :  t
+  -> index:int
+  -> expr:Expr.t
+  -> (t, string) result
+  = "rust_lazy_frame_insert_column"
+
+let insert_column_exn t ~index ~expr =
+  insert_column t ~index ~expr |> Utils.string_result_ok_exn
+;;
+
 external with_streaming : t -> toggle:bool -> t = "rust_lazy_frame_with_streaming"
 external schema : t -> (Schema.t, string) result = "rust_lazy_frame_schema"
 
@@ -2,10 +2,178 @@ open! Core
 
 type t
 
-val scan_parquet : string -> (t, string) result
-val scan_parquet_exn : string -> t
-val scan_csv : string -> (t, string) result
-val scan_csv_exn : string -> t
+(** [path] may be a glob pattern such as ["events/*/*.parquet"], in which case all
+    matching files are scanned as one frame.
+
+    With [hive_partitioning], directory names of the form [key=value] become columns of
+    the frame. A column is an integer if all of its values are, a float if all of them
+    are, and a string otherwise; [hive_schema] gives columns an explicit type instead
+    (e.g. [Utf8] to keep [month=01] as ["01"]).
+
+    Files whose partition values don't satisfy a [filter] on the resulting frame are
+    skipped without being read. Only the parts of the predicate combined with [&&] that
+    refer to nothing but partition columns are used for this, and only when the filter
+    doesn't come after [n_rows], [row_count] or anything else that depends on row
+    positions. *)
+val scan_parquet
+  :  ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string
+  -> (t, string) result
+
+val scan_parquet_exn
+  :  ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string
+  -> t
+
+(** Like [scan_parquet], but scans every one of [paths] (each of which may be a glob
+    pattern) as one frame. *)
+val scan_parquet_paths
+  :  ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string list
+  -> (t, string) result
+
+val scan_parquet_paths_exn
+  :  ?n_rows:int
+  -> ?row_count:string * int
+  -> ?parallel:[ `Auto | `Columns | `None | `Row_groups ]
+  -> ?low_memory:bool
+  -> ?rechunk:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string list
+  -> t
+
+(** [schema] replaces schema inference entirely, while [dtypes] only overrides the
+    types of the columns it mentions. Globs, [hive_partitioning] and [hive_schema]
+    behave as in [scan_parquet]. *)
+val scan_csv
+  :  ?schema:Schema.t
+  -> ?dtypes:Schema.t
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?try_parse_dates:bool
+  -> ?row_count:string * int
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string
+  -> (t, string) result
+
+val scan_csv_exn
+  :  ?schema:Schema.t
+  -> ?dtypes:Schema.t
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?try_parse_dates:bool
+  -> ?row_count:string * int
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string
+  -> t
+
+val scan_csv_paths
+  :  ?schema:Schema.t
+  -> ?dtypes:Schema.t
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?try_parse_dates:bool
+  -> ?row_count:string * int
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string list
+  -> (t, string) result
+
+val scan_csv_paths_exn
+  :  ?schema:Schema.t
+  -> ?dtypes:Schema.t
+  -> ?separator:char
+  -> ?has_header:bool
+  -> ?skip_rows:int
+  -> ?comment_char:char
+  -> ?quote_char:char
+  -> ?null_values:Null_values.t
+  -> ?n_rows:int
+  -> ?infer_schema_length:int
+  -> ?ignore_errors:bool
+  -> ?try_parse_dates:bool
+  -> ?row_count:string * int
+  -> ?encoding:[ `Lossy_utf8 | `Utf8 ]
+  -> ?low_memory:bool
+  -> ?cache:bool
+  -> ?hive_partitioning:bool
+  -> ?hive_schema:Schema.t
+  -> string list
+  -> t
+
+val scan_ipc
+  :  ?n_rows:int
+  -> ?row_count:string * int
+  -> ?memory_map:bool
+  -> ?rechunk:bool
+  -> ?cache:bool
+  -> string
+  -> (t, string) result
+
+val scan_ipc_exn
+  :  ?n_rows:int
+  -> ?row_count:string * int
+  -> ?memory_map:bool
+  -> ?rechunk:bool
+  -> ?cache:bool
+  -> string
+  -> t
+
 val scan_jsonl : string -> (t, string) result
 val scan_jsonl_exn : string -> t
 val explain : ?optimized:bool -> t -> (string, string) result
@@ -27,6 +195,74 @@ val profile : t -> (profile_result, string) result
 val profile_exn : t -> profile_result
 val fetch : t -> n_rows:int -> (Data_frame0.t, string) result
 val fetch_exn : t -> n_rows:int -> Data_frame0.t
+
+(** Runs the query with the streaming engine, writing batches to a Parquet file at
+    [path] as they are produced rather than collecting the result in memory. Fails
+    if some part of the query can't be run in a streaming fashion. *)
+val sink_parquet
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> (unit, string) result
+
+val sink_parquet_exn
+  :  ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> unit
+
+(** Like [sink_parquet], but writes an IPC file.
+
+    There is no [sink_csv]: the version of Polars we build against (0.32.1) can only
+    sink to Parquet and IPC files. To write a CSV file, [collect] the frame and use
+    [Data_frame.write_csv], which holds the whole result in memory. *)
+val sink_ipc
+  :  ?compression:[ `Lz4 | `Zstd ]
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> (unit, string) result
+
+val sink_ipc_exn
+  :  ?compression:[ `Lz4 | `Zstd ]
+  -> ?maintain_order:bool
+  -> t
+  -> string
+  -> unit
+
+(** Writes the frame out like [Data_frame.write_partitioned]. This is not a streaming
+    sink: the whole frame is collected in memory before any file is written, as
+    Polars 0.32.1 has no partitioned sink. *)
+val write_partitioned
+  :  ?format:[ `Csv | `Ipc | `Parquet ]
+  -> ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> partition_by:string list
+  -> string
+  -> ((string * int) list, string) result
+
+val write_partitioned_exn
+  :  ?format:[ `Csv | `Ipc | `Parquet ]
+  -> ?compression:Parquet_compression.t
+  -> ?statistics:bool
+  -> ?row_group_size:int
+  -> ?data_page_size:int
+  -> t
+  -> partition_by:string list
+  -> string
+  -> (string * int) list
+
 val filter : t -> predicate:Expr.t -> t
 val select : t -> exprs:Expr.t list -> t
 val with_columns : t -> exprs:Expr.t list -> t
@@ -86,6 +322,35 @@ val melt
 val sort : ?descending:bool -> ?nulls_last:bool -> t -> by_column:string -> t
 val limit : t -> n:int -> t
 val explode : t -> columns:Expr.t list -> t
+
+(** See [Data_frame.unique]. *)
+val unique
+  :  ?subset:string list
+  -> ?keep:[ `First | `Last | `None | `Any ]
+  -> ?maintain_order:bool
+  -> t
+  -> t
+
+(** See [Data_frame.drop_nulls]. *)
+val drop_nulls : ?subset:string list -> t -> t
+
+(** Like [Data_frame.drop], dropping a missing column is an error, though it is only
+    reported when collecting. *)
+val drop : t -> names:string list -> t
+
+val rename : t -> existing:string -> new_name:string -> t
+
+(** See [Data_frame.rename_many]. *)
+val rename_many : t -> mapping:(string * string) list -> t
+
+(** Like [Data_frame.replace_column] and [Data_frame.insert_column], but with a column
+    computed by [expr] rather than a series. These resolve the schema of [t] to know
+    where its columns are, and fail if it can't be resolved. *)
+val replace_column : t -> index:int -> expr:Expr.t -> (t, string) result
+
+val replace_column_exn : t -> index:int -> expr:Expr.t -> t
+val insert_column : t -> index:int -> expr:Expr.t -> (t, string) result
+val insert_column_exn : t -> index:int -> expr:Expr.t -> t
 val with_streaming : t -> toggle:bool -> t
 val schema : t -> (Schema.t, string) result
 val schema_exn : t -> Schema.t
@@ -0,0 +1,7 @@
+open! Core
+
+type t =
+  | All_columns_single of string
+  | All_columns of string list
+  | Named of (string * string) list
+[@@deriving compare, sexp]
@@ -0,0 +1,11 @@
+open! Core
+
+type t =
+  | Uncompressed
+  | Snappy
+  | Gzip of int option
+  | Lzo
+  | Brotli of int option
+  | Zstd of int option
+  | Lz4_raw
+[@@deriving compare, sexp]
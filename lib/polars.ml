@@ -1,3 +1,5 @@
+module Any_value = Any_value
+module Arrow_c = Arrow_c
 module Common = Common
 module Data_frame = Data_frame
 module Data_type = Data_type
@@ -5,6 +7,10 @@ module Env = Env
 module Expr = Expr
 module Fill_null_strategy = Fill_null_strategy
 module Lazy_frame = Lazy_frame
+module Null_values = Null_values
+module Parquet_compression = Parquet_compression
+module Row_type = Row_type
 module Schema = Schema
 module Series = Series
 module Sql_context = Sql_context
+module String_cache = String_cache
@@ -0,0 +1,18 @@
+open! Core
+
+type _ t =
+  | [] : unit t
+  | ( :: ) : 'a Data_type.Typed.t * 'b t -> ('a option * 'b) t
+
+type 'a flattened = Flattened : 'b t * ('b -> 'a) -> 'a flattened
+
+let rec flatten_custom : type a. a t -> a flattened = function
+  | [] -> Flattened ([], Fn.id)
+  | data_type :: rest ->
+    (match flatten_custom rest, Data_type.Typed.flatten_custom data_type with
+     | Flattened (rest, f_rest), Custom { data_type; f; f_inverse = _ } ->
+       Flattened
+         (data_type :: rest, fun (value, rest) -> Option.map value ~f, f_rest rest)
+     | Flattened (rest, f_rest), data_type ->
+       Flattened (data_type :: rest, fun (value, rest) -> value, f_rest rest))
+;;
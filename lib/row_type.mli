@@ -0,0 +1,15 @@
+open! Core
+
+(** The data types of the columns of a data frame, used to read its rows as nested pairs
+    with one optional component per column, e.g. rows read with [[ Int64; Utf8 ]] have
+    type [int option * (string option * unit)]. *)
+type _ t =
+  | [] : unit t
+  | ( :: ) : 'a Data_type.Typed.t * 'b t -> ('a option * 'b) t
+
+type 'a flattened = Flattened : 'b t * ('b -> 'a) -> 'a flattened
+
+(** [flatten_custom] applies [Data_type.Typed.flatten_custom] to the data type of every
+    column and strips the resulting [Custom]s, returning a function which converts rows
+    of the stripped row type back. *)
+val flatten_custom : 'a t -> 'a flattened
@@ -28,13 +28,9 @@ module T = struct
     | data_type -> createo data_type name values
   ;;
 
-  (* TODO: the astute reader will realize that this is quite terrible when
-     trying to passing float arrays! Unfortunately it's not clear to me how
-     to pass regular arrays safely to Rust, whereas this is definitely safe
-     since [Uniform_array.t] guarantees elements are boxed.
-
-     See https://github.com/mt-caret/polars-ocaml/pull/67 for how naively trying to
-     transmute on the Rust side doesn't work. *)
+  (* [Uniform_array.t] guarantees that elements are boxed, so for floats prefer
+     [create_array], which takes regular (flat) float arrays without boxing every
+     element. *)
   external create'
     :  'a Data_type.Typed.t
     -> string
@@ -49,6 +45,22 @@ module T = struct
     | data_type -> create' data_type name values
   ;;
 
+  (* The Rust side accepts both uniform arrays and flat float arrays (see
+     [UniformArrayValues]), so regular arrays can share the external with [create']. *)
+  external create_array
+    :  'a Data_type.Typed.t
+    -> string
+    -> 'a array
+    -> t
+    = "rust_series_new_array"
+
+  let create_array (type a) (data_type : a Data_type.Typed.t) name values =
+    match Data_type.Typed.flatten_custom data_type with
+    | Custom { data_type; f = _; f_inverse } ->
+      create_array data_type name (Array.map values ~f:f_inverse)
+    | data_type -> create_array data_type name values
+  ;;
+
   external createo'
     :  'a Data_type.Typed.t
     -> string
@@ -63,6 +75,25 @@ module T = struct
     | data_type -> createo' data_type name values
   ;;
 
+  external of_float_array : string -> float array -> t = "rust_series_new_float_array"
+
+  external of_bigarray
+    :  string
+    -> kind:('a, 'b) Bigarray.kind
+    -> ('a, 'b, Bigarray.c_layout) Bigarray.Array1.t
+    -> validity:
+         (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t option
+    -> (t, string) result
+    = "rust_series_new_bigarray"
+
+  let of_bigarray ?validity name values =
+    of_bigarray name ~kind:(Bigarray.Array1.kind values) values ~validity
+  ;;
+
+  let of_bigarray_exn ?validity name values =
+    of_bigarray ?validity name values |> Utils.string_result_ok_exn
+  ;;
+
   let int = create Int64
   let into = createo Int64
   let float = create Float64
@@ -75,17 +106,8 @@ module T = struct
   let string = create Utf8
   let stringo = createo Utf8
 
-  external datetime
-    :  string
-    -> Common.Naive_datetime.t list
-    -> t
-    = "rust_series_new_datetime"
-
-  external datetime_option
-    :  string
-    -> Common.Naive_datetime.t option list
-    -> t
-    = "rust_series_new_datetime_option"
+  let datetime = create (Datetime (Milliseconds, None))
+  let datetime_option = createo (Datetime (Milliseconds, None))
 
   let datetime' name dates =
     datetime name (List.map dates ~f:Common.Naive_datetime.of_date)
@@ -105,18 +127,10 @@ module T = struct
       (List.map times ~f:(Option.map ~f:Common.Naive_datetime.of_time_ns_exn))
   ;;
 
-  external date : string -> Common.Naive_date.t list -> t = "rust_series_new_date"
-
-  let date name dates = date name (List.map dates ~f:Common.Naive_date.of_date)
-
-  external date_option
-    :  string
-    -> Common.Naive_date.t option list
-    -> t
-    = "rust_series_new_date_option"
+  let date name dates = create Date name (List.map dates ~f:Common.Naive_date.of_date)
 
   let date_option name dates =
-    date_option name (List.map dates ~f:(Option.map ~f:Common.Naive_date.of_date))
+    createo Date name (List.map dates ~f:(Option.map ~f:Common.Naive_date.of_date))
   ;;
 
   external date_range
@@ -197,6 +211,60 @@ module T = struct
 
   let get_exn data_type t i = get data_type t i |> Option.value_exn ~here:[%here]
 
+  external get_any_value : t -> int -> (Any_value.t, string) result
+    = "rust_series_get_any_value"
+
+  let get_any_value_exn t i = get_any_value t i |> Utils.string_result_ok_exn
+
+  external to_bigarray
+    :  t
+    -> kind:('a, 'b) Bigarray.kind
+    -> allow_lossy:bool
+    -> ( ('a, 'b, Bigarray.c_layout) Bigarray.Array1.t
+         * (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t option
+       , string )
+       result
+    = "rust_series_to_bigarray"
+
+  let to_bigarray ?(allow_lossy = false) t ~kind = to_bigarray t ~kind ~allow_lossy
+
+  let to_bigarray_exn ?allow_lossy t ~kind =
+    to_bigarray ?allow_lossy t ~kind |> Utils.string_result_ok_exn
+  ;;
+
+  external export_to_c
+    :  t
+    -> schema:int
+    -> array:int
+    -> (unit, string) result
+    = "rust_series_export_to_c"
+
+  let export_to_c t ~schema ~array =
+    let schema = Nativeint.to_int_exn schema in
+    let array = Nativeint.to_int_exn array in
+    export_to_c t ~schema ~array
+  ;;
+
+  let export_to_c_exn t ~schema ~array =
+    export_to_c t ~schema ~array |> Utils.string_result_ok_exn
+  ;;
+
+  external import_from_c
+    :  schema:int
+    -> array:int
+    -> (t, string) result
+    = "rust_series_import_from_c"
+
+  let import_from_c ~schema ~array =
+    let schema = Nativeint.to_int_exn schema in
+    let array = Nativeint.to_int_exn array in
+    import_from_c ~schema ~array
+  ;;
+
+  let import_from_c_exn ~schema ~array =
+    import_from_c ~schema ~array |> Utils.string_result_ok_exn
+  ;;
+
   external name : t -> string = "rust_series_name"
   external rename : t -> name:string -> unit = "rust_series_rename"
   external dtype : t -> Data_type.t = "rust_series_dtype"
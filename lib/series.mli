@@ -5,7 +5,25 @@ type t
 val create : 'a Data_type.Typed.t -> string -> 'a list -> t
 val createo : 'a Data_type.Typed.t -> string -> 'a option list -> t
 val create' : 'a Data_type.Typed.t -> string -> 'a Uniform_array.t -> t
+val create_array : 'a Data_type.Typed.t -> string -> 'a array -> t
 val createo' : 'a Data_type.Typed.t -> string -> 'a option Uniform_array.t -> t
+val of_float_array : string -> float array -> t
+
+(** Copies a Bigarray of any float or integer kind into a series. [validity], if
+    given, must have the same length as the values and marks nulls with 0 (and valid
+    values with anything else), like the mask returned by [to_bigarray]. *)
+val of_bigarray
+  :  ?validity:(int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t
+  -> string
+  -> ('a, 'b, Bigarray.c_layout) Bigarray.Array1.t
+  -> (t, string) result
+
+val of_bigarray_exn
+  :  ?validity:(int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t
+  -> string
+  -> ('a, 'b, Bigarray.c_layout) Bigarray.Array1.t
+  -> t
+
 val int : string -> int list -> t
 val into : string -> int option list -> t
 val float : string -> float list -> t
@@ -58,6 +76,55 @@ val to_list : 'a Data_type.Typed.t -> t -> 'a list
 val to_option_list : 'a Data_type.Typed.t -> t -> 'a option list
 val get : 'a Data_type.Typed.t -> t -> int -> 'a option
 val get_exn : 'a Data_type.Typed.t -> t -> int -> 'a
+
+(** Gets the value at the given index without knowing the data type of the series in
+    advance. Nulls are [Null] rather than an error. *)
+val get_any_value : t -> int -> (Any_value.t, string) result
+
+val get_any_value_exn : t -> int -> Any_value.t
+
+(** Copies the values of a numeric series into a Bigarray of the given [kind], casting
+    them if needed. Only the float32, float64, (un)signed int8 and int16, int32 and int64
+    kinds are supported. The values are always copied, even when the series is a single
+    chunk, as a Bigarray can't keep Polars' memory alive.
+
+    Casts which would lose information, such as truncating floats to integers or
+    rounding integers too large for a float, fail unless [allow_lossy] is [true]. Values
+    that don't fit in [kind] at all always fail.
+
+    If the series contains nulls, a validity mask is also returned, with 1 for valid
+    values and 0 for nulls; the values at null positions are unspecified. *)
+val to_bigarray
+  :  ?allow_lossy:bool
+  -> t
+  -> kind:('a, 'b) Bigarray.kind
+  -> ( ('a, 'b, Bigarray.c_layout) Bigarray.Array1.t
+       * (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t option
+     , string )
+     result
+
+val to_bigarray_exn
+  :  ?allow_lossy:bool
+  -> t
+  -> kind:('a, 'b) Bigarray.kind
+  -> ('a, 'b, Bigarray.c_layout) Bigarray.Array1.t
+     * (int, Bigarray.int8_unsigned_elt, Bigarray.c_layout) Bigarray.Array1.t option
+
+(** Exports the series through the Arrow C Data Interface by writing to the
+    [ArrowSchema] and [ArrowArray] structs at the given addresses, which must have been
+    allocated by the caller. The caller then owns the exported structs and must call
+    their [release] callbacks once it is done with them. *)
+val export_to_c : t -> schema:nativeint -> array:nativeint -> (unit, string) result
+
+val export_to_c_exn : t -> schema:nativeint -> array:nativeint -> unit
+
+(** Imports a series from the [ArrowSchema] and [ArrowArray] structs at the given
+    addresses. Ownership of the data is moved into the series: the structs are marked as
+    released and must not be released again by the caller. *)
+val import_from_c : schema:nativeint -> array:nativeint -> (t, string) result
+
+val import_from_c_exn : schema:nativeint -> array:nativeint -> t
+
 val name : t -> string
 val rename : t -> name:string -> unit
 val dtype : t -> Data_type.t
@@ -0,0 +1,12 @@
+open! Core
+
+external set_enabled : bool -> unit = "rust_enable_string_cache"
+external is_enabled : unit -> bool = "rust_using_string_cache"
+
+let enable () = set_enabled true
+let disable () = set_enabled false
+
+let with_ ~f =
+  enable ();
+  Exn.protect ~f ~finally:disable
+;;
@@ -0,0 +1,17 @@
+open! Core
+
+(** Categorical columns store their values as indices into a table of strings. By
+    default every column gets its own table, so categorical columns created
+    separately (e.g. read from different files) cannot be joined, concatenated or
+    compared with each other. While the global string cache is enabled, all newly
+    created categorical columns share a single table instead.
+
+    The cache is reference counted: it stays enabled until [disable] has been called
+    as many times as [enable], at which point its contents are dropped. *)
+
+val enable : unit -> unit
+val disable : unit -> unit
+val is_enabled : unit -> bool
+
+(** [with_ ~f] enables the global string cache for the duration of [f]. *)
+val with_ : f:(unit -> 'a) -> 'a
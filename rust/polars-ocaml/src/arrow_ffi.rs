@@ -0,0 +1,324 @@
+// Helpers for exchanging Series and DataFrames with other native libraries
+// through the Arrow C Data Interface
+// (https://arrow.apache.org/docs/format/CDataInterface.html) and C Stream
+// Interface (https://arrow.apache.org/docs/format/CStreamInterface.html).
+//
+// Following the spec, the ArrowSchema/ArrowArray/ArrowArrayStream structs
+// themselves are always allocated by the consumer, so OCaml hands us their
+// addresses:
+// - when exporting, we overwrite the (uninitialized) structs, and the consumer
+//   becomes responsible for calling their release callbacks.
+// - when importing, we move the structs out and mark the originals as released,
+//   so the consumer must not release them again; we release them once Polars
+//   no longer needs the underlying buffers.
+use crate::utils::ArrowCStruct;
+use polars::export::arrow::array::{Array, StructArray};
+use polars::export::arrow::bitmap::Bitmap;
+use polars::export::arrow::datatypes::{DataType as ArrowDataType, Field as ArrowField};
+use polars::export::arrow::ffi::{
+    self, ArrowArray, ArrowArrayStream, ArrowArrayStreamReader, ArrowSchema,
+};
+use polars::prelude::*;
+
+// For consumers (e.g. tests) without their own allocator for the C structs.
+// The structs start out released, and dropping them calls their release
+// callbacks if they were exported into but never imported.
+pub fn allocate(kind: ArrowCStruct) -> i64 {
+    match kind {
+        ArrowCStruct::Schema => Box::into_raw(Box::new(ArrowSchema::empty())) as i64,
+        ArrowCStruct::Array => Box::into_raw(Box::new(ArrowArray::empty())) as i64,
+        ArrowCStruct::Stream => Box::into_raw(Box::new(ArrowArrayStream::empty())) as i64,
+    }
+}
+
+pub unsafe fn free(kind: ArrowCStruct, address: i64) -> Result<(), String> {
+    match kind {
+        ArrowCStruct::Schema => drop(Box::from_raw(c_struct::<ArrowSchema>(address)?)),
+        ArrowCStruct::Array => drop(Box::from_raw(c_struct::<ArrowArray>(address)?)),
+        ArrowCStruct::Stream => drop(Box::from_raw(c_struct::<ArrowArrayStream>(address)?)),
+    }
+    Ok(())
+}
+
+unsafe fn c_struct<T>(address: i64) -> Result<*mut T, String> {
+    if address == 0 {
+        Err("Expected a non-null pointer to an Arrow C struct".to_string())
+    } else {
+        Ok(address as usize as *mut T)
+    }
+}
+
+unsafe fn export_array(
+    field: &ArrowField,
+    array: Box<dyn Array>,
+    schema_address: i64,
+    array_address: i64,
+) -> Result<(), String> {
+    let schema = c_struct::<ArrowSchema>(schema_address)?;
+    let array_ = c_struct::<ArrowArray>(array_address)?;
+
+    std::ptr::write(schema, ffi::export_field_to_c(field));
+    std::ptr::write(array_, ffi::export_array_to_c(array));
+    Ok(())
+}
+
+unsafe fn import_array(
+    schema_address: i64,
+    array_address: i64,
+) -> Result<(ArrowField, Box<dyn Array>), String> {
+    let schema = c_struct::<ArrowSchema>(schema_address)?;
+    let array = c_struct::<ArrowArray>(array_address)?;
+
+    let schema = std::ptr::replace(schema, ArrowSchema::empty());
+    let array = std::ptr::replace(array, ArrowArray::empty());
+
+    let field = ffi::import_field_from_c(&schema).map_err(|err| err.to_string())?;
+    let array = ffi::import_array_from_c(array, field.data_type().clone())
+        .map_err(|err| err.to_string())?;
+    Ok((field, array))
+}
+
+// DataFrames are exchanged as struct arrays with one field per column (or a
+// stream of them, one per chunk), which is how Arrow C++ and DuckDB represent
+// record batches over the C Data Interface.
+fn struct_field(data_frame: &DataFrame) -> ArrowField {
+    let fields = data_frame.schema().to_arrow().fields;
+    ArrowField::new("", ArrowDataType::Struct(fields), false)
+}
+
+fn data_frame_to_struct_arrays(
+    mut data_frame: DataFrame,
+) -> Result<(ArrowField, Vec<Box<dyn Array>>), String> {
+    if data_frame.width() == 0 {
+        return Err("Cannot export a DataFrame without columns".to_string());
+    }
+
+    data_frame.align_chunks();
+    let field = struct_field(&data_frame);
+    let arrays = data_frame
+        .iter_chunks()
+        .map(|chunk| StructArray::new(field.data_type().clone(), chunk.into_arrays(), None).boxed())
+        .collect();
+    Ok((field, arrays))
+}
+
+// A null struct row has no DataFrame equivalent, so it becomes a row of
+// nulls. arrow2's FFI import has already sliced the children by the struct's
+// offset, but leaves its validity to us.
+fn with_struct_validity(values: &dyn Array, validity: Option<&Bitmap>) -> Box<dyn Array> {
+    match (validity, values.data_type()) {
+        (None, _) | (Some(_), ArrowDataType::Null) => values.to_boxed(),
+        (Some(validity), _) => {
+            let validity = match values.validity() {
+                Some(values_validity) => values_validity & validity,
+                None => validity.clone(),
+            };
+            values.with_validity(Some(validity))
+        }
+    }
+}
+
+fn struct_array_to_data_frame(array: &dyn Array) -> Result<DataFrame, String> {
+    let array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| format!("Expected a struct array, got {:?}", array.data_type()))?;
+
+    array
+        .fields()
+        .iter()
+        .zip(array.values())
+        .map(|(field, values)| {
+            let values = with_struct_validity(values.as_ref(), array.validity());
+            Series::try_from((field.name.as_str(), values))
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .and_then(DataFrame::new)
+        .map_err(|err| err.to_string())
+}
+
+pub unsafe fn export_series(
+    series: &Series,
+    schema_address: i64,
+    array_address: i64,
+) -> Result<(), String> {
+    let series = series.rechunk();
+    let field = series.field().to_arrow();
+    export_array(&field, series.to_arrow(0), schema_address, array_address)
+}
+
+pub unsafe fn import_series(schema_address: i64, array_address: i64) -> Result<Series, String> {
+    let (field, array) = import_array(schema_address, array_address)?;
+    Series::try_from((field.name.as_str(), array)).map_err(|err| err.to_string())
+}
+
+pub unsafe fn export_data_frame(
+    mut data_frame: DataFrame,
+    schema_address: i64,
+    array_address: i64,
+) -> Result<(), String> {
+    data_frame.rechunk();
+    let (field, arrays) = data_frame_to_struct_arrays(data_frame)?;
+    let array = arrays
+        .into_iter()
+        .next()
+        .ok_or_else(|| "Cannot export a DataFrame without chunks".to_string())?;
+    export_array(&field, array, schema_address, array_address)
+}
+
+pub unsafe fn import_data_frame(
+    schema_address: i64,
+    array_address: i64,
+) -> Result<DataFrame, String> {
+    let (_field, array) = import_array(schema_address, array_address)?;
+    struct_array_to_data_frame(array.as_ref())
+}
+
+pub unsafe fn export_stream(data_frame: DataFrame, stream_address: i64) -> Result<(), String> {
+    let (field, arrays) = data_frame_to_struct_arrays(data_frame)?;
+    let stream = c_struct::<ArrowArrayStream>(stream_address)?;
+
+    std::ptr::write(
+        stream,
+        ffi::export_iterator(Box::new(arrays.into_iter().map(Ok)), field),
+    );
+    Ok(())
+}
+
+pub unsafe fn import_stream(stream_address: i64) -> Result<DataFrame, String> {
+    let stream = c_struct::<ArrowArrayStream>(stream_address)?;
+    let stream = Box::new(std::ptr::replace(stream, ArrowArrayStream::empty()));
+
+    let mut reader = ArrowArrayStreamReader::try_new(stream).map_err(|err| err.to_string())?;
+    let fields = match reader.field().data_type() {
+        ArrowDataType::Struct(fields) => fields.clone(),
+        data_type => {
+            return Err(format!(
+                "Expected a stream of struct arrays, got {:?}",
+                data_type
+            ))
+        }
+    };
+
+    let mut data_frame = DataFrame::new_no_checks(
+        fields
+            .iter()
+            .map(|field| Series::new_empty(&field.name, &DataType::from(field.data_type())))
+            .collect(),
+    );
+    while let Some(array) = reader.next() {
+        let array = array.map_err(|err| err.to_string())?;
+        data_frame
+            .vstack_mut(&struct_array_to_data_frame(array.as_ref())?)
+            .map_err(|err| err.to_string())?;
+    }
+    Ok(data_frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Stand-ins for the structs which a C consumer would allocate.
+    fn address<T>(c_struct: &mut T) -> i64 {
+        c_struct as *mut T as i64
+    }
+
+    fn chunked_series() -> Series {
+        let mut series = Series::new("a", [Some(1), None, Some(3)]);
+        series.append(&Series::new("a", [None, Some(5)])).unwrap();
+        assert_eq!(series.n_chunks(), 2);
+        series
+    }
+
+    fn chunked_data_frame() -> DataFrame {
+        let mut data_frame = df!(
+            "a" => [Some(1), None, Some(3)],
+            "b" => [Some("x"), Some("y"), None]
+        )
+        .unwrap();
+        data_frame
+            .vstack_mut(&df!("a" => [None, Some(5)], "b" => [None, Some("z")]).unwrap())
+            .unwrap();
+        assert_eq!(data_frame.n_chunks(), 2);
+        data_frame
+    }
+
+    #[test]
+    fn series_round_trip() {
+        let series = chunked_series();
+        let (mut schema, mut array) = (ArrowSchema::empty(), ArrowArray::empty());
+        let imported = unsafe {
+            export_series(&series, address(&mut schema), address(&mut array)).unwrap();
+            import_series(address(&mut schema), address(&mut array)).unwrap()
+        };
+        assert_eq!(imported.name(), "a");
+        assert!(imported.series_equal_missing(&series));
+    }
+
+    #[test]
+    fn data_frame_round_trip() {
+        let data_frame = chunked_data_frame();
+        let (mut schema, mut array) = (ArrowSchema::empty(), ArrowArray::empty());
+        let imported = unsafe {
+            export_data_frame(
+                data_frame.clone(),
+                address(&mut schema),
+                address(&mut array),
+            )
+            .unwrap();
+            import_data_frame(address(&mut schema), address(&mut array)).unwrap()
+        };
+        assert!(imported.frame_equal_missing(&data_frame));
+    }
+
+    #[test]
+    fn import_struct_validity_and_offset() {
+        let data_frame = df!("a" => [1, 2, 3, 4], "b" => ["w", "x", "y", "z"]).unwrap();
+        let field = struct_field(&data_frame);
+        let validity = Bitmap::from([true, false, true, true]);
+        let chunk = data_frame.iter_chunks().next().unwrap();
+        let array = StructArray::new(
+            field.data_type().clone(),
+            chunk.into_arrays(),
+            Some(validity),
+        )
+        .sliced(1, 3)
+        .boxed();
+
+        let (mut schema, mut c_array) = (ArrowSchema::empty(), ArrowArray::empty());
+        let imported = unsafe {
+            export_array(&field, array, address(&mut schema), address(&mut c_array)).unwrap();
+            import_data_frame(address(&mut schema), address(&mut c_array)).unwrap()
+        };
+        let expected = df!(
+            "a" => [None, Some(3), Some(4)],
+            "b" => [None, Some("y"), Some("z")]
+        )
+        .unwrap();
+        assert!(imported.frame_equal_missing(&expected));
+    }
+
+    #[test]
+    fn stream_round_trip() {
+        let data_frame = chunked_data_frame();
+        let mut stream = ArrowArrayStream::empty();
+        let imported = unsafe {
+            export_stream(data_frame.clone(), address(&mut stream)).unwrap();
+            import_stream(address(&mut stream)).unwrap()
+        };
+        assert_eq!(imported.n_chunks(), 2);
+        assert!(imported.frame_equal_missing(&data_frame));
+    }
+
+    #[test]
+    fn null_addresses() {
+        let series = chunked_series();
+        let mut array = ArrowArray::empty();
+        let result = unsafe { export_series(&series, 0, address(&mut array)) };
+        assert_eq!(
+            result,
+            Err("Expected a non-null pointer to an Arrow C struct".to_string())
+        );
+    }
+}
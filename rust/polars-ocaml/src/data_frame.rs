@@ -1,13 +1,22 @@
+use crate::arrow_ffi;
+use crate::hive;
+use crate::rows;
 use crate::utils::*;
-use ocaml_interop::{DynBox, OCaml, OCamlFloat, OCamlInt, OCamlList, OCamlRef, ToOCaml};
+use ocaml_interop::{
+    BoxRoot, DynBox, OCaml, OCamlBytes, OCamlFloat, OCamlInt, OCamlList, OCamlRef, ToOCaml,
+};
 use polars::prelude::*;
 use polars_ocaml_macros::ocaml_interop_export;
 use smartstring::{LazyCompact, SmartString};
 use std::cell::RefCell;
 use std::fs::File;
+use std::io::{Cursor, Read};
+use std::mem::ManuallyDrop;
+use std::os::unix::io::{FromRawFd, RawFd};
+use std::path::Path;
 use std::rc::Rc;
 
-use crate::series::PolarsSeries;
+use crate::series::{series_to_boxrooted_ocaml_list, PolarsSeries};
 
 pub type PolarsDataFrame = Rc<RefCell<DataFrame>>;
 
@@ -26,100 +35,417 @@ fn rust_data_frame_new(
 }
 
 #[ocaml_interop_export]
+fn rust_data_frame_of_rows(
+    cr: &mut &mut OCamlRuntime,
+    rows: OCamlRef<OCamlList<OCamlList<AnyValue<'static>>>>,
+    schema: OCamlRef<Option<DynBox<Schema>>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let rows: Vec<Vec<PolarsAnyValueResult>> = rows.to_rust(cr);
+    let rows = rows
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|PolarsAnyValueResult(value)| value)
+                .collect()
+        })
+        .collect();
+    let schema = schema
+        .to_rust::<Option<Abstract<Schema>>>(cr)
+        .map(|Abstract(schema)| schema);
+
+    rows::data_frame_from_rows(rows, schema)
+        .map(|df| Abstract(Rc::new(RefCell::new(df))))
+        .to_ocaml(cr)
+}
+
+#[ocaml_interop_export(raise_on_err)]
 fn rust_data_frame_read_csv(
     cr: &mut &mut OCamlRuntime,
-    path: OCamlRef<String>,
+    source: OCamlRef<ReadSource>,
     schema: OCamlRef<Option<DynBox<Schema>>>,
     try_parse_dates: OCamlRef<Option<bool>>,
+    separator: OCamlRef<Option<OCamlInt>>,
+    has_header: OCamlRef<Option<bool>>,
+    skip_rows: OCamlRef<Option<OCamlInt>>,
+    comment_char: OCamlRef<Option<OCamlInt>>,
+    quote_char: OCamlRef<Option<OCamlInt>>,
+    null_values: OCamlRef<Option<NullValues>>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    columns: OCamlRef<Option<OCamlList<String>>>,
+    projection: OCamlRef<Option<OCamlList<OCamlInt>>>,
+    infer_schema_length: OCamlRef<Option<OCamlInt>>,
+    ignore_errors: OCamlRef<Option<bool>>,
+    encoding: OCamlRef<Option<CsvEncoding>>,
+    low_memory: OCamlRef<Option<bool>>,
+    rechunk: OCamlRef<Option<bool>>,
 ) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
-    let path: String = path.to_rust(cr);
+    let source: ReadSource = source.to_rust(cr);
     let schema = schema
         .to_rust::<Option<Abstract<Schema>>>(cr)
         .map(|Abstract(schema)| Arc::new(schema));
     let try_parse_dates: Option<bool> = try_parse_dates.to_rust(cr);
-
-    CsvReader::from_path(path)
-        .and_then(|csv_reader| {
-            let csv_reader = csv_reader.with_dtypes(schema);
-            match try_parse_dates {
-                None => csv_reader,
-                Some(try_parse_dates) => csv_reader.with_try_parse_dates(try_parse_dates),
+    let separator = separator
+        .to_rust::<Coerce<_, Option<i64>, Option<u8>>>(cr)
+        .get()?;
+    let has_header: Option<bool> = has_header.to_rust(cr);
+    let skip_rows = skip_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let comment_char = comment_char
+        .to_rust::<Coerce<_, Option<i64>, Option<u8>>>(cr)
+        .get()?;
+    let quote_char = quote_char
+        .to_rust::<Coerce<_, Option<i64>, Option<u8>>>(cr)
+        .get()?;
+    let null_values = null_values
+        .to_rust::<Option<PolarsNullValues>>(cr)
+        .map(|PolarsNullValues(null_values)| null_values);
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let columns: Option<Vec<String>> = columns.to_rust(cr);
+    let projection = projection
+        .to_rust::<Option<Vec<i64>>>(cr)
+        .map(|projection| {
+            projection
+                .into_iter()
+                .map(usize::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|err| err.to_string())?;
+    let infer_schema_length = infer_schema_length
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let ignore_errors: Option<bool> = ignore_errors.to_rust(cr);
+    let encoding = encoding
+        .to_rust::<Option<PolarsCsvEncoding>>(cr)
+        .map(|PolarsCsvEncoding(encoding)| encoding);
+    let low_memory: Option<bool> = low_memory.to_rust(cr);
+    let rechunk: Option<bool> = rechunk.to_rust(cr);
+
+    source
+        .open()
+        .and_then(|reader| {
+            let mut csv_reader = CsvReader::new(reader)
+                .with_dtypes(
+                    schema
+                        .as_deref()
+                        .map(|schema| Arc::new(decimals_as_utf8(schema))),
+                )
+                .with_comment_char(comment_char)
+                .with_null_values(null_values)
+                .with_n_rows(n_rows)
+                .with_columns(columns)
+                .with_projection(projection);
+            if let Some(try_parse_dates) = try_parse_dates {
+                csv_reader = csv_reader.with_try_parse_dates(try_parse_dates);
             }
-            .finish()
+            if let Some(separator) = separator {
+                csv_reader = csv_reader.with_delimiter(separator);
+            }
+            if let Some(has_header) = has_header {
+                csv_reader = csv_reader.has_header(has_header);
+            }
+            if let Some(skip_rows) = skip_rows {
+                csv_reader = csv_reader.with_skip_rows(skip_rows);
+            }
+            // Polars defaults to double quotes, so we only override the quote
+            // character when one is explicitly passed.
+            if let Some(quote_char) = quote_char {
+                csv_reader = csv_reader.with_quote_char(Some(quote_char));
+            }
+            if let Some(infer_schema_length) = infer_schema_length {
+                csv_reader = csv_reader.infer_schema(Some(infer_schema_length));
+            }
+            if let Some(ignore_errors) = ignore_errors {
+                csv_reader = csv_reader.with_ignore_errors(ignore_errors);
+            }
+            if let Some(encoding) = encoding {
+                csv_reader = csv_reader.with_encoding(encoding);
+            }
+            if let Some(low_memory) = low_memory {
+                csv_reader = csv_reader.low_memory(low_memory);
+            }
+            if let Some(rechunk) = rechunk {
+                csv_reader = csv_reader.with_rechunk(rechunk);
+            }
+            csv_reader
+                .finish()
+                .and_then(|df| match &schema {
+                    Some(schema) => parse_decimal_columns(df, schema),
+                    None => Ok(df),
+                })
+                .map_err(|err| err.to_string())
         })
         .map(|df| Abstract(Rc::new(RefCell::new(df))))
-        .map_err(|err| err.to_string())
         .to_ocaml(cr)
 }
 
-#[ocaml_interop_export]
+#[ocaml_interop_export(raise_on_err)]
 fn rust_data_frame_write_csv(
     cr: &mut &mut OCamlRuntime,
     data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
-    path: OCamlRef<String>,
-) -> OCaml<Result<(), String>> {
+    target: OCamlRef<WriteTarget>,
+    has_header: OCamlRef<bool>,
+    separator: OCamlRef<OCamlInt>,
+    quote_char: OCamlRef<OCamlInt>,
+    quote_style: OCamlRef<QuoteStyle>,
+    null_value: OCamlRef<String>,
+    line_terminator: OCamlRef<String>,
+    float_precision: OCamlRef<Option<OCamlInt>>,
+    date_format: OCamlRef<Option<String>>,
+    datetime_format: OCamlRef<Option<String>>,
+    time_format: OCamlRef<Option<String>>,
+) -> OCaml<Result<Option<OCamlBytes>, String>> {
     let Abstract(data_frame) = data_frame.to_rust(cr);
-    let path: String = path.to_rust(cr);
-
-    File::create(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            CsvWriter::new(&file)
-                .finish(&mut data_frame.borrow_mut())
-                .map_err(|err| err.to_string())
+    let target: WriteTarget = target.to_rust(cr);
+    let has_header: bool = has_header.to_rust(cr);
+    let separator = separator.to_rust::<Coerce<_, i64, u8>>(cr).get()?;
+    let quote_char = quote_char.to_rust::<Coerce<_, i64, u8>>(cr).get()?;
+    let PolarsQuoteStyle(quote_style) = quote_style.to_rust(cr);
+    let null_value: String = null_value.to_rust(cr);
+    let line_terminator: String = line_terminator.to_rust(cr);
+    let float_precision = float_precision
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let date_format: Option<String> = date_format.to_rust(cr);
+    let datetime_format: Option<String> = datetime_format.to_rust(cr);
+    let time_format: Option<String> = time_format.to_rust(cr);
+
+    target
+        .create()
+        .and_then(|mut sink| {
+            let mut data_frame =
+                decimals_to_strings(&data_frame.borrow()).map_err(|err| err.to_string())?;
+            CsvWriter::new(&mut sink)
+                .has_header(has_header)
+                .with_delimiter(separator)
+                .with_quoting_char(quote_char)
+                .with_quote_style(quote_style)
+                .with_null_value(null_value)
+                .with_line_terminator(line_terminator)
+                .with_float_precision(float_precision)
+                .with_date_format(date_format)
+                .with_datetime_format(datetime_format)
+                .with_time_format(time_format)
+                .finish(&mut data_frame)
+                .map_err(|err| err.to_string())?;
+            Ok(sink.into_buffer())
         })
         .to_ocaml(cr)
 }
 
-#[ocaml_interop_export]
+#[ocaml_interop_export(raise_on_err)]
 fn rust_data_frame_read_parquet(
     cr: &mut &mut OCamlRuntime,
-    path: OCamlRef<String>,
+    source: OCamlRef<ReadSource>,
+    columns: OCamlRef<Option<OCamlList<String>>>,
+    projection: OCamlRef<Option<OCamlList<OCamlInt>>>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    row_count: OCamlRef<Option<(String, OCamlInt)>>,
+    parallel: OCamlRef<Option<ParallelStrategy>>,
+    low_memory: OCamlRef<Option<bool>>,
+    rechunk: OCamlRef<Option<bool>>,
 ) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
-    let path: String = path.to_rust(cr);
-
-    File::open(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            ParquetReader::new(file)
-                .finish()
-                .map_err(|err| err.to_string())
+    let source: ReadSource = source.to_rust(cr);
+    let columns: Option<Vec<String>> = columns.to_rust(cr);
+    let projection = projection
+        .to_rust::<Option<Vec<i64>>>(cr)
+        .map(|projection| {
+            projection
+                .into_iter()
+                .map(usize::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|err| err.to_string())?;
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let row_count = row_count
+        .to_rust::<Option<PolarsRowCount>>(cr)
+        .map(|PolarsRowCount(row_count)| row_count);
+    let parallel = parallel
+        .to_rust::<Option<PolarsParallelStrategy>>(cr)
+        .map(|PolarsParallelStrategy(parallel)| parallel);
+    let low_memory: Option<bool> = low_memory.to_rust(cr);
+    let rechunk: Option<bool> = rechunk.to_rust(cr);
+
+    source
+        .open()
+        .and_then(|reader| {
+            let mut parquet_reader = ParquetReader::new(reader)
+                .with_columns(columns)
+                .with_projection(projection)
+                .with_n_rows(n_rows)
+                .with_row_count(row_count);
+            if let Some(parallel) = parallel {
+                parquet_reader = parquet_reader.read_parallel(parallel);
+            }
+            if let Some(low_memory) = low_memory {
+                parquet_reader = parquet_reader.set_low_memory(low_memory);
+            }
+            if let Some(rechunk) = rechunk {
+                parquet_reader = parquet_reader.set_rechunk(rechunk);
+            }
+            parquet_reader.finish().map_err(|err| err.to_string())
         })
         .map(|df| Abstract(Rc::new(RefCell::new(df))))
         .to_ocaml(cr)
 }
 
-#[ocaml_interop_export]
+#[ocaml_interop_export(raise_on_err)]
 fn rust_data_frame_write_parquet(
     cr: &mut &mut OCamlRuntime,
     data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
-    path: OCamlRef<String>,
-) -> OCaml<Result<(), String>> {
+    target: OCamlRef<WriteTarget>,
+    compression: OCamlRef<ParquetCompression>,
+    statistics: OCamlRef<bool>,
+    row_group_size: OCamlRef<Option<OCamlInt>>,
+    data_page_size: OCamlRef<Option<OCamlInt>>,
+) -> OCaml<Result<(OCamlInt, Option<OCamlBytes>), String>> {
     let Abstract(data_frame) = data_frame.to_rust(cr);
-    let path: String = path.to_rust(cr);
+    let target: WriteTarget = target.to_rust(cr);
+    let PolarsParquetCompression(compression) = compression.to_rust(cr);
+    let statistics: bool = statistics.to_rust(cr);
+    let row_group_size = row_group_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let data_page_size = data_page_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
 
-    File::create(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            ParquetWriter::new(file)
+    target
+        .create()
+        .and_then(|mut sink| {
+            ParquetWriter::new(&mut sink)
+                .with_compression(compression)
+                .with_statistics(statistics)
+                .with_row_group_size(row_group_size)
+                .with_data_pagesize_limit(data_page_size)
                 .finish(&mut data_frame.borrow_mut())
-                .map(|_file_size_in_bytes| ())
+                .map(|file_size_in_bytes| (OCamlIntable(file_size_in_bytes), sink.into_buffer()))
                 .map_err(|err| err.to_string())
         })
         .to_ocaml(cr)
 }
 
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_write_partitioned(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    root: OCamlRef<String>,
+    partition_by: OCamlRef<OCamlList<String>>,
+    format: OCamlRef<FileFormat>,
+    compression: OCamlRef<ParquetCompression>,
+    statistics: OCamlRef<bool>,
+    row_group_size: OCamlRef<Option<OCamlInt>>,
+    data_page_size: OCamlRef<Option<OCamlInt>>,
+) -> OCaml<Result<OCamlList<(String, OCamlInt)>, String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let root: String = root.to_rust(cr);
+    let partition_by: Vec<String> = partition_by.to_rust(cr);
+    let format: FileFormat = format.to_rust(cr);
+    let PolarsParquetCompression(compression) = compression.to_rust(cr);
+    let statistics: bool = statistics.to_rust(cr);
+    let row_group_size = row_group_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let data_pagesize_limit = data_page_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+
+    let parquet_options = hive::ParquetOptions {
+        compression,
+        statistics,
+        row_group_size,
+        data_pagesize_limit,
+    };
+
+    hive::write_partitioned(
+        &data_frame.borrow(),
+        Path::new(&root),
+        &partition_by,
+        format,
+        &parquet_options,
+    )
+    .map(|manifest| {
+        manifest
+            .into_iter()
+            .map(|(path, rows)| (path, OCamlIntable(rows)))
+            .collect::<Vec<_>>()
+    })
+    .to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_export_to_c(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    schema: OCamlRef<OCamlInt>,
+    array: OCamlRef<OCamlInt>,
+) -> OCaml<Result<(), String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let schema: i64 = schema.to_rust(cr);
+    let array: i64 = array.to_rust(cr);
+
+    let data_frame = data_frame.borrow().clone();
+
+    unsafe { arrow_ffi::export_data_frame(data_frame, schema, array) }.to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_import_from_c(
+    cr: &mut &mut OCamlRuntime,
+    schema: OCamlRef<OCamlInt>,
+    array: OCamlRef<OCamlInt>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let schema: i64 = schema.to_rust(cr);
+    let array: i64 = array.to_rust(cr);
+
+    unsafe { arrow_ffi::import_data_frame(schema, array) }
+        .map(|data_frame| Abstract(Rc::new(RefCell::new(data_frame))))
+        .to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_export_stream_to_c(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    stream: OCamlRef<OCamlInt>,
+) -> OCaml<Result<(), String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let stream: i64 = stream.to_rust(cr);
+
+    let data_frame = data_frame.borrow().clone();
+
+    unsafe { arrow_ffi::export_stream(data_frame, stream) }.to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_import_stream_from_c(
+    cr: &mut &mut OCamlRuntime,
+    stream: OCamlRef<OCamlInt>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let stream: i64 = stream.to_rust(cr);
+
+    unsafe { arrow_ffi::import_stream(stream) }
+        .map(|data_frame| Abstract(Rc::new(RefCell::new(data_frame))))
+        .to_ocaml(cr)
+}
+
 #[ocaml_interop_export]
 fn rust_data_frame_read_json(
     cr: &mut &mut OCamlRuntime,
-    path: OCamlRef<String>,
+    source: OCamlRef<ReadSource>,
 ) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
-    let path: String = path.to_rust(cr);
+    let source: ReadSource = source.to_rust(cr);
 
-    File::open(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            JsonReader::new(file)
+    source
+        .open()
+        .and_then(|reader| {
+            JsonReader::new(reader)
                 .finish()
                 .map_err(|err| err.to_string())
         })
@@ -131,18 +457,19 @@ fn rust_data_frame_read_json(
 fn rust_data_frame_write_json(
     cr: &mut &mut OCamlRuntime,
     data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
-    path: OCamlRef<String>,
-) -> OCaml<Result<(), String>> {
+    target: OCamlRef<WriteTarget>,
+) -> OCaml<Result<Option<OCamlBytes>, String>> {
     let Abstract(data_frame) = data_frame.to_rust(cr);
-    let path: String = path.to_rust(cr);
+    let target: WriteTarget = target.to_rust(cr);
 
-    File::create(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            JsonWriter::new(file)
+    target
+        .create()
+        .and_then(|mut sink| {
+            JsonWriter::new(&mut sink)
                 .with_json_format(JsonFormat::Json)
                 .finish(&mut data_frame.borrow_mut())
-                .map_err(|err| err.to_string())
+                .map_err(|err| err.to_string())?;
+            Ok(sink.into_buffer())
         })
         .to_ocaml(cr)
 }
@@ -150,14 +477,14 @@ fn rust_data_frame_write_json(
 #[ocaml_interop_export]
 fn rust_data_frame_read_jsonl(
     cr: &mut &mut OCamlRuntime,
-    path: OCamlRef<String>,
+    source: OCamlRef<ReadSource>,
 ) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
-    let path: String = path.to_rust(cr);
+    let source: ReadSource = source.to_rust(cr);
 
-    File::open(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            JsonLineReader::new(file)
+    source
+        .open()
+        .and_then(|reader| {
+            JsonLineReader::new(reader)
                 .finish()
                 .map_err(|err| err.to_string())
         })
@@ -169,22 +496,198 @@ fn rust_data_frame_read_jsonl(
 fn rust_data_frame_write_jsonl(
     cr: &mut &mut OCamlRuntime,
     data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
-    path: OCamlRef<String>,
-) -> OCaml<Result<(), String>> {
+    target: OCamlRef<WriteTarget>,
+) -> OCaml<Result<Option<OCamlBytes>, String>> {
     let Abstract(data_frame) = data_frame.to_rust(cr);
-    let path: String = path.to_rust(cr);
+    let target: WriteTarget = target.to_rust(cr);
 
-    File::create(path)
-        .map_err(|err| err.to_string())
-        .and_then(|file| {
-            JsonWriter::new(file)
+    target
+        .create()
+        .and_then(|mut sink| {
+            JsonWriter::new(&mut sink)
                 .with_json_format(JsonFormat::JsonLines)
                 .finish(&mut data_frame.borrow_mut())
-                .map_err(|err| err.to_string())
+                .map_err(|err| err.to_string())?;
+            Ok(sink.into_buffer())
+        })
+        .to_ocaml(cr)
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_read_ipc(
+    cr: &mut &mut OCamlRuntime,
+    source: OCamlRef<ReadSource>,
+    columns: OCamlRef<Option<OCamlList<String>>>,
+    projection: OCamlRef<Option<OCamlList<OCamlInt>>>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    row_count: OCamlRef<Option<(String, OCamlInt)>>,
+    memory_map: OCamlRef<Option<bool>>,
+    rechunk: OCamlRef<Option<bool>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let source: ReadSource = source.to_rust(cr);
+    let columns: Option<Vec<String>> = columns.to_rust(cr);
+    let projection = projection
+        .to_rust::<Option<Vec<i64>>>(cr)
+        .map(|projection| {
+            projection
+                .into_iter()
+                .map(usize::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|err| err.to_string())?;
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let row_count = row_count
+        .to_rust::<Option<PolarsRowCount>>(cr)
+        .map(|PolarsRowCount(row_count)| row_count);
+    let memory_map: Option<bool> = memory_map.to_rust(cr);
+    let rechunk: Option<bool> = rechunk.to_rust(cr);
+
+    source
+        .open()
+        .and_then(|reader| {
+            let mut ipc_reader = IpcReader::new(reader)
+                .with_columns(columns)
+                .with_projection(projection)
+                .with_n_rows(n_rows)
+                .with_row_count(row_count);
+            if let Some(memory_map) = memory_map {
+                ipc_reader = ipc_reader.memory_mapped(memory_map);
+            }
+            if let Some(rechunk) = rechunk {
+                ipc_reader = ipc_reader.set_rechunk(rechunk);
+            }
+            ipc_reader.finish().map_err(|err| err.to_string())
         })
+        .map(|df| Abstract(Rc::new(RefCell::new(df))))
+        .to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_write_ipc(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    target: OCamlRef<WriteTarget>,
+    compression: OCamlRef<Option<IpcCompression>>,
+) -> OCaml<Result<Option<OCamlBytes>, String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let target: WriteTarget = target.to_rust(cr);
+    let compression = compression
+        .to_rust::<Option<PolarsIpcCompression>>(cr)
+        .map(|PolarsIpcCompression(compression)| compression);
+
+    target
+        .create()
+        .and_then(|mut sink| {
+            IpcWriter::new(&mut sink)
+                .with_compression(compression)
+                .finish(&mut data_frame.borrow_mut())
+                .map_err(|err| err.to_string())?;
+            Ok(sink.into_buffer())
+        })
+        .to_ocaml(cr)
+}
+
+// The file descriptor is owned by the OCaml side, so we wrap the `File` in a
+// `ManuallyDrop` to avoid closing it once we're done with it.
+unsafe fn borrow_file_descr(fd: RawFd) -> ManuallyDrop<File> {
+    ManuallyDrop::new(File::from_raw_fd(fd))
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_write_ipc_stream(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    fd: OCamlRef<OCamlInt>,
+    compression: OCamlRef<Option<IpcCompression>>,
+) -> OCaml<Result<(), String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let fd = fd.to_rust::<Coerce<_, i64, RawFd>>(cr).get()?;
+    let compression = compression
+        .to_rust::<Option<PolarsIpcCompression>>(cr)
+        .map(|PolarsIpcCompression(compression)| compression.into());
+
+    let mut file = unsafe { borrow_file_descr(fd) };
+
+    IpcStreamWriter::new(&mut *file)
+        .with_compression(compression)
+        .finish(&mut data_frame.borrow_mut())
+        .map_err(|err| err.to_string())
         .to_ocaml(cr)
 }
 
+// Reads exactly one IPC stream (schema message, record batches and the
+// end-of-stream marker) from the file descriptor, leaving any subsequent streams
+// unread. Returns `None` if the file descriptor is already at EOF.
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_read_ipc_stream(
+    cr: &mut &mut OCamlRuntime,
+    fd: OCamlRef<OCamlInt>,
+    columns: OCamlRef<Option<OCamlList<String>>>,
+    projection: OCamlRef<Option<OCamlList<OCamlInt>>>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    row_count: OCamlRef<Option<(String, OCamlInt)>>,
+    rechunk: OCamlRef<Option<bool>>,
+) -> OCaml<Result<Option<DynBox<PolarsDataFrame>>, String>> {
+    let fd = fd.to_rust::<Coerce<_, i64, RawFd>>(cr).get()?;
+    let columns: Option<Vec<String>> = columns.to_rust(cr);
+    let projection = projection
+        .to_rust::<Option<Vec<i64>>>(cr)
+        .map(|projection| {
+            projection
+                .into_iter()
+                .map(usize::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|err| err.to_string())?;
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let row_count = row_count
+        .to_rust::<Option<PolarsRowCount>>(cr)
+        .map(|PolarsRowCount(row_count)| row_count);
+    let rechunk: Option<bool> = rechunk.to_rust(cr);
+
+    let mut file = unsafe { borrow_file_descr(fd) };
+
+    // Reading from the file descriptor can block, e.g. on a pipe.
+    let data_frame = cr.releasing_runtime(|| {
+        // Peek at the first message length so that we can distinguish a clean
+        // EOF between streams from a truncated stream.
+        let mut prefix = Vec::with_capacity(4);
+        (&mut *file)
+            .take(4)
+            .read_to_end(&mut prefix)
+            .map_err(|err| err.to_string())?;
+        if prefix.is_empty() {
+            return Ok(None);
+        }
+        // The stream is always read up to its end-of-stream marker and only
+        // then cut down to `n_rows`: stopping early would leave the rest of its
+        // record batches to be misread as the start of the next stream.
+        let mut ipc_stream_reader = IpcStreamReader::new(Cursor::new(prefix).chain(&mut *file))
+            .with_columns(columns)
+            .with_projection(projection)
+            .with_row_count(row_count);
+        if let Some(rechunk) = rechunk {
+            ipc_stream_reader = ipc_stream_reader.set_rechunk(rechunk);
+        }
+        ipc_stream_reader
+            .finish()
+            .map(|df| match n_rows {
+                Some(n_rows) => Some(df.slice(0, n_rows)),
+                None => Some(df),
+            })
+            .map_err(|err| err.to_string())
+    });
+    let data_frame = data_frame.map(|df| df.map(|df| Abstract(Rc::new(RefCell::new(df)))));
+
+    data_frame.to_ocaml(cr)
+}
+
 #[ocaml_interop_export]
 fn rust_data_frame_clear(
     cr: &mut &mut OCamlRuntime,
@@ -240,6 +743,86 @@ fn rust_data_frame_lazy(
     })
 }
 
+// Polars only evaluates expressions through LazyFrames, so eager operations run
+// a single-step plan over the DataFrame (which is cheap to clone since its
+// columns are reference counted). Like Polars' Python bindings, we skip the
+// optimizer, which has nothing to gain on such plans.
+fn eager_op(
+    data_frame: &DataFrame,
+    op: impl FnOnce(LazyFrame) -> LazyFrame,
+) -> PolarsResult<PolarsDataFrame> {
+    op(data_frame.clone().lazy().without_optimizations())
+        .collect()
+        .map(|df| Rc::new(RefCell::new(df)))
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_select(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    exprs: OCamlRef<OCamlList<DynBox<Expr>>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let exprs = unwrap_abstract_vec(exprs.to_rust(cr));
+
+    dyn_box_result!(cr, |data_frame| {
+        eager_op(&data_frame.borrow(), |lazy_frame| lazy_frame.select(&exprs))
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_with_columns(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    exprs: OCamlRef<OCamlList<DynBox<Expr>>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let exprs = unwrap_abstract_vec(exprs.to_rust(cr));
+
+    dyn_box_result!(cr, |data_frame| {
+        eager_op(&data_frame.borrow(), |lazy_frame| {
+            lazy_frame.with_columns(&exprs)
+        })
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_filter(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    predicate: OCamlRef<DynBox<Expr>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let Abstract(predicate) = predicate.to_rust(cr);
+
+    dyn_box_result!(cr, |data_frame| {
+        eager_op(&data_frame.borrow(), |lazy_frame| {
+            lazy_frame.filter(predicate)
+        })
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_groupby(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    is_stable: OCamlRef<bool>,
+    by: OCamlRef<OCamlList<DynBox<Expr>>>,
+    agg: OCamlRef<OCamlList<DynBox<Expr>>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let is_stable = is_stable.to_rust(cr);
+    let by = unwrap_abstract_vec(by.to_rust(cr));
+    let agg = unwrap_abstract_vec(agg.to_rust(cr));
+
+    dyn_box_result!(cr, |data_frame| {
+        eager_op(&data_frame.borrow(), |lazy_frame| {
+            let groupby = if is_stable {
+                lazy_frame.groupby_stable(by)
+            } else {
+                lazy_frame.groupby(by)
+            };
+            groupby.agg(agg)
+        })
+    })
+}
+
 #[ocaml_interop_export]
 fn rust_data_frame_column(
     cr: &mut &mut OCamlRuntime,
@@ -257,6 +840,92 @@ fn rust_data_frame_column(
     })
 }
 
+#[ocaml_interop_export]
+fn rust_data_frame_get_any_value(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    name: OCamlRef<String>,
+    index: OCamlRef<OCamlInt>,
+) -> OCaml<Result<AnyValue<'static>, String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let data_frame = data_frame.borrow();
+    let name: String = name.to_rust(cr);
+    let index: i64 = index.to_rust(cr);
+
+    data_frame
+        .column(&name)
+        .map_err(|err| err.to_string())
+        .and_then(|series| any_value_at(series, index))
+        .to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_get_rows(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    offset: OCamlRef<OCamlInt>,
+    length: OCamlRef<OCamlInt>,
+) -> OCaml<Result<OCamlList<OCamlList<AnyValue<'static>>>, String>> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let data_frame = data_frame.borrow();
+    let offset = offset.to_rust::<Coerce<_, i64, usize>>(cr).get();
+    let length = length.to_rust::<Coerce<_, i64, usize>>(cr).get();
+
+    offset
+        .and_then(|offset| rows::any_value_rows(&data_frame, offset, length?))
+        .to_ocaml(cr)
+}
+
+// Rows in the typed API are nested pairs with one optional value per column,
+// see Row_type.t.
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_get_typed_rows(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    row_type: OCamlRef<GADTRowType>,
+    offset: OCamlRef<OCamlInt>,
+    length: OCamlRef<OCamlInt>,
+) -> OCaml<DummyBoxRoot> {
+    let Abstract(data_frame) = data_frame.to_rust(cr);
+    let data_types: Vec<GADTDataType> = row_type.to_rust(cr);
+    let offset = offset.to_rust::<Coerce<_, i64, usize>>(cr).get()?;
+    let length = length.to_rust::<Coerce<_, i64, usize>>(cr).get()?;
+
+    let data_frame = rows::rows_slice(&data_frame.borrow(), offset, length)?;
+    if data_types.len() != data_frame.width() {
+        return Err(format!(
+            "Row type has {} columns but the DataFrame has {}",
+            data_types.len(),
+            data_frame.width()
+        ));
+    }
+
+    // Build the nested pairs from the innermost (unit) one outwards.
+    let mut rows: Vec<DummyBoxRoot> = (0..data_frame.height())
+        .map(|_| {
+            let unit: BoxRoot<()> = ().to_ocaml(cr).root();
+            unsafe { DummyBoxRoot::new(unit) }
+        })
+        .collect();
+    for (column, data_type) in data_frame.get_columns().iter().zip(&data_types).rev() {
+        let values: Vec<DummyBoxRoot> = series_to_boxrooted_ocaml_list(cr, data_type, column, true)
+            .map_err(|err| format!("Column {}: {}", column.name(), err))?
+            .interpret::<OCamlList<DummyBoxRoot>>(cr)
+            .to_rust();
+        rows = values
+            .into_iter()
+            .zip(rows)
+            .map(|(value, rest)| {
+                let row: BoxRoot<(DummyBoxRoot, DummyBoxRoot)> = (value, rest).to_ocaml(cr).root();
+                unsafe { DummyBoxRoot::new(row) }
+            })
+            .collect();
+    }
+
+    let rows: BoxRoot<OCamlList<DummyBoxRoot>> = rows.to_ocaml(cr).root();
+    unsafe { DummyBoxRoot::new(rows) }.to_ocaml(cr)
+}
+
 #[ocaml_interop_export]
 fn rust_data_frame_columns(
     cr: &mut &mut OCamlRuntime,
@@ -657,6 +1326,138 @@ fn rust_data_frame_explode(
     })
 }
 
+#[ocaml_interop_export]
+fn rust_data_frame_unique(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    subset: OCamlRef<Option<OCamlList<String>>>,
+    keep: OCamlRef<UniqueKeepStrategy>,
+    maintain_order: OCamlRef<bool>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let subset: Option<Vec<String>> = subset.to_rust(cr);
+    let PolarsUniqueKeepStrategy(keep) = keep.to_rust(cr);
+    let maintain_order: bool = maintain_order.to_rust(cr);
+
+    dyn_box_result!(cr, |data_frame| {
+        let data_frame = data_frame.borrow();
+        data_frame
+            .unique_impl(maintain_order, subset.as_deref(), keep, None)
+            .map(|df| Rc::new(RefCell::new(df)))
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_drop_nulls(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    subset: OCamlRef<Option<OCamlList<String>>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let subset: Option<Vec<String>> = subset.to_rust(cr);
+
+    dyn_box_result!(cr, |data_frame| {
+        let data_frame = data_frame.borrow();
+        data_frame
+            .drop_nulls(subset.as_deref())
+            .map(|df| Rc::new(RefCell::new(df)))
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_drop(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    names: OCamlRef<OCamlList<String>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let names: Vec<String> = names.to_rust(cr);
+
+    dyn_box_result!(cr, |data_frame| {
+        let data_frame = data_frame.borrow();
+        // Unlike `DataFrame::drop`, `DataFrame::drop_many` ignores missing
+        // columns, which would hide typos.
+        names
+            .iter()
+            .try_for_each(|name| data_frame.try_find_idx_by_name(name).map(|_| ()))
+            .map(|()| Rc::new(RefCell::new(data_frame.drop_many(&names))))
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_data_frame_rename(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    mapping: OCamlRef<OCamlList<(String, String)>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let mapping: Vec<(String, String)> = mapping.to_rust(cr);
+
+    dyn_box_result!(cr, |data_frame| {
+        // Columns are renamed all at once (as `LazyFrame::rename` does) rather
+        // than one after the other, so that columns can swap names.
+        let mut data_frame = data_frame.borrow().clone();
+        let mut names = data_frame.get_column_names_owned();
+        mapping
+            .iter()
+            .try_for_each(|(existing, new)| {
+                names[data_frame.try_find_idx_by_name(existing)?] = new.into();
+                Ok::<_, PolarsError>(())
+            })
+            .and_then(|()| data_frame.set_column_names(&names))
+            .map(|()| Rc::new(RefCell::new(data_frame)))
+    })
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_replace_column(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    index: OCamlRef<OCamlInt>,
+    series: OCamlRef<DynBox<PolarsSeries>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let index = index.to_rust::<Coerce<_, i64, usize>>(cr).get()?;
+
+    dyn_box_result!(cr, |data_frame, series| {
+        let mut data_frame = data_frame.borrow().clone();
+        let series = series.borrow().clone();
+        // `DataFrame::replace_at_idx` doesn't check names, so we do what
+        // `DataFrame::insert_at_idx` does, except for the replaced column.
+        let result = match data_frame.find_idx_by_name(series.name()) {
+            Some(existing) if existing != index => Err(polars_err!(
+                Duplicate:
+                "column with name {:?} is already present in the dataframe",
+                series.name()
+            )),
+            _ => data_frame.replace_at_idx(index, series).map(|_| ()),
+        };
+        result.map(|()| Rc::new(RefCell::new(data_frame)))
+    })
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_data_frame_insert_column(
+    cr: &mut &mut OCamlRuntime,
+    data_frame: OCamlRef<DynBox<PolarsDataFrame>>,
+    index: OCamlRef<OCamlInt>,
+    series: OCamlRef<DynBox<PolarsSeries>>,
+) -> OCaml<Result<DynBox<PolarsDataFrame>, String>> {
+    let index = index.to_rust::<Coerce<_, i64, usize>>(cr).get()?;
+
+    dyn_box_result!(cr, |data_frame, series| {
+        let mut data_frame = data_frame.borrow().clone();
+        let series = series.borrow().clone();
+        // `DataFrame::insert_at_idx` panics on out of bounds indices.
+        let result = if index > data_frame.width() {
+            Err(polars_err!(
+                ComputeError:
+                "unable to insert at index {}, the dataframe has only {} columns",
+                index,
+                data_frame.width()
+            ))
+        } else {
+            data_frame.insert_at_idx(index, series).map(|_| ())
+        };
+        result.map(|()| Rc::new(RefCell::new(data_frame)))
+    })
+}
+
 #[ocaml_interop_export]
 fn rust_data_frame_schema(
     cr: &mut &mut OCamlRuntime,
@@ -98,16 +98,34 @@ fn rust_expr_lit(
         GADTDataType::Float64 => lit(value.interpret::<OCamlFloat>(cr).to_rust::<f64>()),
         GADTDataType::Utf8 => lit(value.interpret::<String>(cr).to_rust::<String>()),
         GADTDataType::Binary => lit(value.interpret::<OCamlBytes>(cr).to_rust::<Vec<u8>>()),
-        GADTDataType::List(data_type) => {
-            // Since there is no direct way to create a List-based literal, we
-            // create a one-element series instead, and use that.
-            let series = crate::series::series_new(
-                cr,
-                &GADTDataType::List(data_type),
-                "series",
-                vec![value],
-                false,
-            )?;
+        GADTDataType::Date => {
+            let Abstract(date) = value.interpret::<DynBox<NaiveDate>>(cr).to_rust();
+            Expr::Literal(LiteralValue::Date(naive_date_to_days(date)))
+        }
+        GADTDataType::Datetime(timeunit, timezone) => {
+            let Abstract(datetime) = value.interpret::<DynBox<NaiveDateTime>>(cr).to_rust();
+            let timestamp = naive_datetime_to_timestamp(datetime, timeunit)?;
+            Expr::Literal(LiteralValue::DateTime(timestamp, timeunit, timezone))
+        }
+        GADTDataType::Duration(timeunit) => {
+            let OCamlInt63(nanoseconds) = value.interpret::<OCamlInt63>(cr).to_rust();
+            Expr::Literal(LiteralValue::Duration(
+                nanoseconds / nanoseconds_per_unit(timeunit),
+                timeunit,
+            ))
+        }
+        GADTDataType::Time => {
+            let OCamlInt63(nanoseconds) = value.interpret::<OCamlInt63>(cr).to_rust();
+            Expr::Literal(LiteralValue::Time(time_of_day_to_nanoseconds(nanoseconds)?))
+        }
+        data_type @ (GADTDataType::List(_)
+        | GADTDataType::Struct(_)
+        | GADTDataType::Decimal(_, _)
+        | GADTDataType::Array(_, _)) => {
+            // Since there is no direct way to create a List, Struct, Decimal
+            // or Array-based literal, we create a one-element series instead,
+            // and use that.
+            let series = crate::series::series_new(cr, &data_type, "series", vec![value], false)?;
             lit(series)
         }
     };
@@ -316,6 +334,75 @@ expr_op!(rust_expr_arg_max, |expr| expr.arg_max());
 expr_op!(rust_expr_arg_min, |expr| expr.arg_min());
 expr_op!(rust_expr_count, |expr| expr.count());
 
+// The number of digits which `decimal_mean` adds to the scale of its input, so
+// that e.g. the mean of 1 and 2 is 1.5 rather than being rounded to 2.
+const DECIMAL_MEAN_EXTRA_SCALE: usize = 4;
+
+// The largest precision an i128 based Decimal can have.
+const DECIMAL_MAX_PRECISION: usize = 38;
+
+// Divides the exact sum by the count, rounding half away from zero to the
+// scale of the input plus DECIMAL_MEAN_EXTRA_SCALE.
+fn decimal_mean(series: Series) -> PolarsResult<Option<Series>> {
+    let ca = series.decimal()?;
+    if let Some(precision) = ca.precision() {
+        polars_ensure!(
+            precision + DECIMAL_MEAN_EXTRA_SCALE <= DECIMAL_MAX_PRECISION,
+            ComputeError:
+            "decimal_mean of {} would need precision {}, more than the maximum of {}",
+            series.name(),
+            precision + DECIMAL_MEAN_EXTRA_SCALE,
+            DECIMAL_MAX_PRECISION
+        );
+    }
+    let count = (ca.len() - ca.null_count()) as i128;
+    let mean = ca
+        .sum()
+        .filter(|_| count > 0)
+        .map(|sum| {
+            let sum = sum
+                .checked_mul(10_i128.pow(DECIMAL_MEAN_EXTRA_SCALE as u32))
+                .ok_or_else(|| {
+                    polars_err!(ComputeError: "overflow computing the mean of {}", series.name())
+                })?;
+            let (quotient, remainder) = (sum / count, sum % count);
+            Ok(if 2 * remainder.abs() >= count {
+                quotient + sum.signum()
+            } else {
+                quotient
+            })
+        })
+        .transpose()?;
+    Int128Chunked::from_slice_options(series.name(), &[mean])
+        .into_decimal(
+            ca.precision()
+                .map(|precision| precision + DECIMAL_MEAN_EXTRA_SCALE),
+            ca.scale() + DECIMAL_MEAN_EXTRA_SCALE,
+        )
+        .map(|ca| Some(ca.into_series()))
+}
+
+#[ocaml_interop_export]
+fn rust_expr_decimal_mean(
+    cr: &mut &mut OCamlRuntime,
+    expr: OCamlRef<DynBox<Expr>>,
+) -> OCaml<DynBox<Expr>> {
+    // `apply` runs once per group in aggregation contexts, producing a
+    // one-element list per group which `first` unwraps.
+    dyn_box!(cr, |expr| expr
+        .apply(
+            decimal_mean,
+            GetOutput::map_dtype(|data_type| match data_type {
+                DataType::Decimal(precision, scale) => DataType::Decimal(
+                    precision.map(|precision| precision + DECIMAL_MEAN_EXTRA_SCALE),
+                    scale.map(|scale| scale + DECIMAL_MEAN_EXTRA_SCALE),
+                ),
+                data_type => data_type.clone(),
+            }),
+        )
+        .first())
+}
+
 #[ocaml_interop_export]
 fn rust_expr_count_(cr: &mut &mut OCamlRuntime, unit: OCamlRef<()>) -> OCaml<DynBox<Expr>> {
     let () = unit.to_rust(cr);
@@ -994,3 +1081,43 @@ fn rust_expr_list_eval(
     let parallel = parallel.to_rust(cr);
     dyn_box!(cr, |expr, other| expr.list().eval(other, parallel))
 }
+
+expr_op!(rust_expr_arr_sum, |expr| expr.arr().sum());
+expr_op!(rust_expr_arr_min, |expr| expr.arr().min());
+expr_op!(rust_expr_arr_max, |expr| expr.arr().max());
+
+#[ocaml_interop_export]
+fn rust_expr_arr_unique(
+    cr: &mut &mut OCamlRuntime,
+    expr: OCamlRef<DynBox<Expr>>,
+    maintain_order: OCamlRef<bool>,
+) -> OCaml<DynBox<Expr>> {
+    let maintain_order: bool = maintain_order.to_rust(cr);
+    dyn_box!(cr, |expr| if maintain_order {
+        expr.arr().unique_stable()
+    } else {
+        expr.arr().unique()
+    })
+}
+
+// Polars 0.32.1 has no `arr().to_list()`, but casting an Array to a List of the
+// same inner data type does the same thing.
+#[ocaml_interop_export]
+fn rust_expr_arr_to_list(
+    cr: &mut &mut OCamlRuntime,
+    expr: OCamlRef<DynBox<Expr>>,
+) -> OCaml<DynBox<Expr>> {
+    expr_series_map(
+        cr,
+        expr,
+        |series| {
+            let ca = series.array()?;
+            ca.cast(&DataType::List(Box::new(ca.inner_dtype())))
+                .map(Some)
+        },
+        GetOutput::map_dtype(|data_type| match data_type {
+            DataType::Array(inner, _) => DataType::List(inner.clone()),
+            data_type => data_type.clone(),
+        }),
+    )
+}
@@ -0,0 +1,400 @@
+// Support for scanning several files as one LazyFrame, optionally treating
+// `key=value` directory names as Hive-style partition columns, and for writing
+// DataFrames out in that layout.
+//
+// TODO: Polars 0.32.1's lazy readers only understand a single path or glob
+// and know nothing about Hive partitioning, so file discovery and partition
+// pruning are done here. Once we upgrade to a Polars with native Hive support
+// this should be replaced by the corresponding ScanArgs options.
+use crate::utils::{decimals_to_strings, FileFormat};
+use polars::io::RowCount;
+use polars::prelude::*;
+use std::any::Any;
+use std::fs::{File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+// Polars (like Hive and Spark) writes null partition values out as this
+// directory name.
+pub const HIVE_NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+const FILE_INDEX_COLUMN: &str = "__polars_ocaml_file_index";
+
+fn is_glob(path: &str) -> bool {
+    path.contains('*') || path.contains('?') || path.contains('[')
+}
+
+fn expand_paths(paths: &[String]) -> PolarsResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if is_glob(path) {
+            let matches = glob::glob(path).map_err(
+                |err| polars_err!(ComputeError: "invalid glob pattern {}: {}", path, err),
+            )?;
+            for file in matches {
+                let file = file.map_err(|err| polars_err!(ComputeError: "{}", err))?;
+                if file.is_file() {
+                    files.push(file);
+                }
+            }
+        } else {
+            files.push(PathBuf::from(path));
+        }
+    }
+    polars_ensure!(
+        !files.is_empty(),
+        ComputeError: "no matching files found in {:?}", paths
+    );
+    Ok(files)
+}
+
+fn parse_partitions(file: &Path) -> Vec<(String, String)> {
+    file.parent()
+        .into_iter()
+        .flat_map(|parent| parent.components())
+        .filter_map(|component| {
+            let component = component.as_os_str().to_str()?;
+            let (key, value) = component.split_once('=')?;
+            if key.is_empty() {
+                None
+            } else {
+                Some((key.to_string(), value.to_string()))
+            }
+        })
+        .collect()
+}
+
+// Builds one Series per partition key, with one row per file. Keys named in
+// `hive_schema` are cast to the given data type. Other columns are Int64 if
+// every value parses as an integer, Float64 if every value parses as a float
+// and Utf8 otherwise.
+fn partition_columns(files: &[PathBuf], hive_schema: Option<&Schema>) -> PolarsResult<Vec<Series>> {
+    let partitions: Vec<Vec<(String, String)>> =
+        files.iter().map(|file| parse_partitions(file)).collect();
+    let keys: Vec<String> = partitions[0].iter().map(|(key, _)| key.clone()).collect();
+
+    for (file, file_partitions) in files.iter().zip(&partitions) {
+        let file_keys = file_partitions.iter().map(|(key, _)| key);
+        polars_ensure!(
+            file_keys.eq(keys.iter()),
+            ComputeError: "Hive partition keys of {} do not match {:?}", file.display(), keys
+        );
+    }
+
+    keys.iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let values: Vec<Option<&str>> = partitions
+                .iter()
+                .map(|file_partitions| {
+                    let value = file_partitions[i].1.as_str();
+                    (value != HIVE_NULL_PARTITION).then_some(value)
+                })
+                .collect();
+            if let Some(data_type) = hive_schema.and_then(|schema| schema.get(key)) {
+                Series::new(key, values).strict_cast(data_type).map_err(|err| {
+                    polars_err!(
+                        ComputeError: "cannot cast Hive partition {} to {}: {}", key, data_type, err
+                    )
+                })
+            } else if let Some(values) = values
+                .iter()
+                .map(|value| value.map(|value| value.parse::<i64>().ok()).transpose())
+                .collect::<Option<Vec<Option<i64>>>>()
+            {
+                Ok(Series::new(key, values))
+            } else if let Some(values) = values
+                .iter()
+                .map(|value| value.map(|value| value.parse::<f64>().ok()).transpose())
+                .collect::<Option<Vec<Option<f64>>>>()
+            {
+                Ok(Series::new(key, values))
+            } else {
+                Ok(Series::new(key, values))
+            }
+        })
+        .collect()
+}
+
+fn scan_file<F>(scan: &F, file: &Path, n_rows: Option<usize>) -> PolarsResult<LazyFrame>
+where
+    F: Fn(&Path, Option<usize>, Option<RowCount>) -> PolarsResult<LazyFrame>,
+{
+    scan(file, n_rows, None)
+        .map_err(|err| polars_err!(ComputeError: "error while reading {}: {}", file.display(), err))
+}
+
+// Splits a predicate of the form `a & b & c` into `[a, b, c]`.
+fn conjuncts(predicate: Expr, acc: &mut Vec<Expr>) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            conjuncts(*left, acc);
+            conjuncts(*right, acc);
+        }
+        predicate => acc.push(predicate),
+    }
+}
+
+// A scan of Hive-partitioned files. Polars pushes filters on the scanned frame
+// down into `scan`: the conjuncts which only refer to partition columns are
+// evaluated against the partition values to decide which files to read at
+// all, and the rest are applied to the rows of the files that are read.
+struct HiveScan<F> {
+    files: Vec<PathBuf>,
+    // One Series per partition key, with one row per file.
+    partitions: Vec<Series>,
+    n_rows: Option<usize>,
+    scan: F,
+}
+
+impl<F> HiveScan<F> {
+    fn is_partition_predicate(&self, predicate: &Expr) -> bool {
+        predicate.into_iter().all(|expr| match expr {
+            Expr::Column(name) => self
+                .partitions
+                .iter()
+                .any(|partition| partition.name() == name.as_ref()),
+            // Expressions that depend on more than one row at a time would
+            // give different answers on the partition values.
+            Expr::Agg(_) | Expr::Count | Expr::Window { .. } => false,
+            Expr::Wildcard | Expr::Nth(_) | Expr::Columns(_) | Expr::DtypeColumn(_) => false,
+            _ => true,
+        })
+    }
+
+    // Returns the indices of the files whose partition values satisfy
+    // `predicate`.
+    fn select_files(&self, predicate: Expr) -> PolarsResult<Vec<usize>> {
+        let file_index = IdxCa::from_vec(
+            FILE_INDEX_COLUMN,
+            (0..self.files.len() as IdxSize).collect(),
+        )
+        .into_series();
+        let columns = std::iter::once(file_index)
+            .chain(self.partitions.iter().cloned())
+            .collect();
+        let selected = DataFrame::new(columns)?
+            .lazy()
+            .filter(predicate)
+            .select([col(FILE_INDEX_COLUMN)])
+            .collect()?;
+        let selected = selected.column(FILE_INDEX_COLUMN)?.idx()?;
+        Ok(selected.into_no_null_iter().map(|i| i as usize).collect())
+    }
+}
+
+impl<F> AnonymousScan for HiveScan<F>
+where
+    F: Fn(&Path, Option<usize>, Option<RowCount>) -> PolarsResult<LazyFrame>
+        + Send
+        + Sync
+        + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        let mut predicates = Vec::new();
+        if let Some(predicate) = scan_opts.predicate {
+            conjuncts(predicate, &mut predicates);
+        }
+        let (partition_predicates, row_predicates): (Vec<_>, Vec<_>) = predicates
+            .into_iter()
+            .partition(|predicate| self.is_partition_predicate(predicate));
+
+        let files = match partition_predicates.into_iter().reduce(Expr::and) {
+            Some(predicate) => self.select_files(predicate)?,
+            None => (0..self.files.len()).collect(),
+        };
+        if files.is_empty() {
+            return Ok(DataFrame::from(scan_opts.schema.as_ref()));
+        }
+
+        let lazy_frames = files
+            .into_iter()
+            .map(|i| {
+                let lazy_frame = scan_file(&self.scan, &self.files[i], self.n_rows)?;
+                let partition_values: Vec<Expr> = self
+                    .partitions
+                    .iter()
+                    .map(|partition| lit(partition.slice(i as i64, 1)).alias(partition.name()))
+                    .collect();
+                Ok(lazy_frame.with_columns(partition_values))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut lazy_frame = concat(&lazy_frames, UnionArgs::default())?;
+        if let Some(predicate) = row_predicates.into_iter().reduce(Expr::and) {
+            lazy_frame = lazy_frame.filter(predicate);
+        }
+        lazy_frame.collect()
+    }
+}
+
+// Scans each of `paths` (which may be glob patterns) with `scan` and
+// concatenates the results. With `hive_partitioning`, partition columns parsed
+// from the directory names are appended to each file's columns, and filters on
+// the partition columns skip the files they exclude (see `HiveScan`). Filters
+// only reach the scan if nothing that depends on row positions, such as
+// `n_rows`, `row_count` or a slice, comes before them.
+//
+// `n_rows` and `row_count` apply to the concatenated frame, not to each file.
+pub fn scan_files<F>(
+    paths: Vec<String>,
+    hive_partitioning: bool,
+    hive_schema: Option<Schema>,
+    n_rows: Option<usize>,
+    row_count: Option<RowCount>,
+    scan: F,
+) -> PolarsResult<LazyFrame>
+where
+    F: Fn(&Path, Option<usize>, Option<RowCount>) -> PolarsResult<LazyFrame>
+        + Send
+        + Sync
+        + 'static,
+{
+    polars_ensure!(
+        hive_partitioning || hive_schema.is_none(),
+        ComputeError: "a Hive schema requires Hive partitioning to be enabled"
+    );
+    if let [path] = paths.as_slice() {
+        if !hive_partitioning {
+            return scan(Path::new(path), n_rows, row_count);
+        }
+    }
+
+    let files = expand_paths(&paths)?;
+    let mut lazy_frame = if hive_partitioning {
+        let partitions = partition_columns(&files, hive_schema.as_ref())?;
+        let mut schema = scan_file(&scan, &files[0], Some(0))?
+            .schema()?
+            .as_ref()
+            .clone();
+        for partition in &partitions {
+            schema.with_column(partition.name().into(), partition.dtype().clone());
+        }
+        let hive_scan = HiveScan {
+            files,
+            partitions,
+            n_rows,
+            scan,
+        };
+        let args = ScanArgsAnonymous {
+            schema: Some(schema),
+            name: "HIVE SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(hive_scan), args)?
+    } else {
+        let lazy_frames = files
+            .iter()
+            .map(|file| scan_file(&scan, file, n_rows))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        concat(&lazy_frames, UnionArgs::default())?
+    };
+    if let Some(n_rows) = n_rows {
+        lazy_frame = lazy_frame.slice(0, n_rows as IdxSize);
+    }
+    if let Some(row_count) = row_count {
+        lazy_frame = lazy_frame.with_row_count(&row_count.name, Some(row_count.offset));
+    }
+    Ok(lazy_frame)
+}
+
+fn partition_directory(value: AnyValue) -> Result<String, String> {
+    let value = match value {
+        AnyValue::Null => HIVE_NULL_PARTITION.to_string(),
+        value => match value.get_str() {
+            Some(value) => value.to_string(),
+            None => value.to_string(),
+        },
+    };
+    if value.contains(std::path::is_separator) {
+        Err(format!(
+            "partition value {:?} cannot be used as a directory name",
+            value
+        ))
+    } else {
+        Ok(value)
+    }
+}
+
+// Creates the first of `part-0.<extension>`, `part-1.<extension>`, ... which
+// doesn't exist yet in `directory`, so that writing to the same root again adds
+// files next to the existing ones instead of overwriting them.
+fn create_part_file(directory: &Path, format: FileFormat) -> Result<(String, File), String> {
+    let mut index = 0;
+    loop {
+        let path = directory.join(format!("part-{}.{}", index, format.extension()));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path.to_string_lossy().into_owned(), file)),
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => index += 1,
+            Err(err) => return Err(format!("{}: {}", path.display(), err)),
+        }
+    }
+}
+
+// The Parquet writer options which apply to partitioned writes.
+pub struct ParquetOptions {
+    pub compression: ParquetCompression,
+    pub statistics: bool,
+    pub row_group_size: Option<usize>,
+    pub data_pagesize_limit: Option<usize>,
+}
+
+// Writes one file per distinct combination of `partition_by` values to
+// `root/key1=value1/key2=value2/part-<n>.<extension>`, leaving the partition
+// columns themselves out of the files. `parquet_options` only apply to Parquet
+// files. Returns the path and number of rows of every file written.
+pub fn write_partitioned(
+    data_frame: &DataFrame,
+    root: &Path,
+    partition_by: &[String],
+    format: FileFormat,
+    parquet_options: &ParquetOptions,
+) -> Result<Vec<(String, usize)>, String> {
+    let partitions = data_frame
+        .partition_by_stable(partition_by, true)
+        .map_err(|err| err.to_string())?;
+
+    partitions
+        .into_iter()
+        .map(|partition| {
+            let mut directory = root.to_path_buf();
+            for key in partition_by {
+                let value = partition
+                    .column(key)
+                    .and_then(|column| column.get(0))
+                    .map_err(|err| err.to_string())?;
+                directory.push(format!("{}={}", key, partition_directory(value)?));
+            }
+            std::fs::create_dir_all(&directory).map_err(|err| err.to_string())?;
+
+            let (path, mut file) = create_part_file(&directory, format)?;
+            let mut partition = partition.drop_many(partition_by);
+            match format {
+                FileFormat::Csv => decimals_to_strings(&partition)
+                    .and_then(|mut partition| CsvWriter::new(&mut file).finish(&mut partition)),
+                FileFormat::Ipc => IpcWriter::new(&mut file).finish(&mut partition),
+                FileFormat::Parquet => ParquetWriter::new(&mut file)
+                    .with_compression(parquet_options.compression)
+                    .with_statistics(parquet_options.statistics)
+                    .with_row_group_size(parquet_options.row_group_size)
+                    .with_data_pagesize_limit(parquet_options.data_pagesize_limit)
+                    .finish(&mut partition)
+                    .map(|_| ()),
+            }
+            .map_err(|err| err.to_string())?;
+
+            Ok((path, partition.height()))
+        })
+        .collect()
+}
@@ -3,38 +3,247 @@
 // attribute alongside the proc macro invocation doesn't work, which probably
 // is fixable; disabling the warning file-wide in the interim.
 #![allow(clippy::type_complexity)]
+use crate::hive;
 use crate::utils::*;
 use ocaml_interop::{DynBox, OCaml, OCamlInt, OCamlList, OCamlRef, ToOCaml};
+use polars::io::RowCount;
 use polars::prelude::*;
 use polars_ocaml_macros::ocaml_interop_export;
 use smartstring::{LazyCompact, SmartString};
 use std::rc::Rc;
 use std::{cell::RefCell, path::Path};
 
-#[ocaml_interop_export]
+#[ocaml_interop_export(raise_on_err)]
 fn rust_lazy_frame_scan_csv(
     cr: &mut &mut OCamlRuntime,
-    path: OCamlRef<String>,
+    paths: OCamlRef<OCamlList<String>>,
+    schema: OCamlRef<Option<DynBox<Schema>>>,
+    dtypes: OCamlRef<Option<DynBox<Schema>>>,
+    separator: OCamlRef<Option<OCamlInt>>,
+    has_header: OCamlRef<Option<bool>>,
+    skip_rows: OCamlRef<Option<OCamlInt>>,
+    comment_char: OCamlRef<Option<OCamlInt>>,
+    quote_char: OCamlRef<Option<OCamlInt>>,
+    null_values: OCamlRef<Option<NullValues>>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    infer_schema_length: OCamlRef<Option<OCamlInt>>,
+    ignore_errors: OCamlRef<Option<bool>>,
+    try_parse_dates: OCamlRef<Option<bool>>,
+    row_count: OCamlRef<Option<(String, OCamlInt)>>,
+    encoding: OCamlRef<Option<CsvEncoding>>,
+    low_memory: OCamlRef<Option<bool>>,
+    cache: OCamlRef<Option<bool>>,
+    hive_partitioning: OCamlRef<Option<bool>>,
+    hive_schema: OCamlRef<Option<DynBox<Schema>>>,
 ) -> OCaml<Result<DynBox<LazyFrame>, String>> {
-    let path: String = path.to_rust(cr);
+    let paths: Vec<String> = paths.to_rust(cr);
+    let schema = schema
+        .to_rust::<Option<Abstract<Schema>>>(cr)
+        .map(|Abstract(schema)| Arc::new(schema));
+    let dtypes = dtypes
+        .to_rust::<Option<Abstract<Schema>>>(cr)
+        .map(|Abstract(dtypes)| dtypes);
+    let separator = separator
+        .to_rust::<Coerce<_, Option<i64>, Option<u8>>>(cr)
+        .get()?;
+    let has_header: Option<bool> = has_header.to_rust(cr);
+    let skip_rows = skip_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let comment_char = comment_char
+        .to_rust::<Coerce<_, Option<i64>, Option<u8>>>(cr)
+        .get()?;
+    let quote_char = quote_char
+        .to_rust::<Coerce<_, Option<i64>, Option<u8>>>(cr)
+        .get()?;
+    let null_values = null_values
+        .to_rust::<Option<PolarsNullValues>>(cr)
+        .map(|PolarsNullValues(null_values)| null_values);
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let infer_schema_length = infer_schema_length
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let ignore_errors: Option<bool> = ignore_errors.to_rust(cr);
+    let try_parse_dates: Option<bool> = try_parse_dates.to_rust(cr);
+    let row_count = row_count
+        .to_rust::<Option<PolarsRowCount>>(cr)
+        .map(|PolarsRowCount(row_count)| row_count);
+    let encoding = encoding
+        .to_rust::<Option<PolarsCsvEncoding>>(cr)
+        .map(|PolarsCsvEncoding(encoding)| encoding);
+    let low_memory: Option<bool> = low_memory.to_rust(cr);
+    let cache: Option<bool> = cache.to_rust(cr);
+    let hive_partitioning: Option<bool> = hive_partitioning.to_rust(cr);
+    let hive_schema = hive_schema
+        .to_rust::<Option<Abstract<Schema>>>(cr)
+        .map(|Abstract(hive_schema)| hive_schema);
+
+    // Decimal columns are read as strings, see `decimals_to_strings`.
+    let decimals: Schema = schema
+        .as_deref()
+        .into_iter()
+        .chain(dtypes.as_ref())
+        .flat_map(|schema| schema.iter())
+        .filter(|(_, data_type)| matches!(data_type, DataType::Decimal(_, _)))
+        .map(|(name, data_type)| Field::new(name, data_type.clone()))
+        .collect();
+    let schema = schema.map(|schema| Arc::new(decimals_as_utf8(&schema)));
+    let dtypes = dtypes.map(|dtypes| decimals_as_utf8(&dtypes));
+
+    let scan = move |path: &Path, n_rows: Option<usize>, row_count: Option<RowCount>| {
+        let mut lazy_csv_reader = LazyCsvReader::new(path)
+            .with_dtype_overwrite(dtypes.as_ref())
+            .with_comment_char(comment_char)
+            .with_null_values(null_values.clone())
+            .with_n_rows(n_rows)
+            .with_row_count(row_count);
+        if let Some(schema) = &schema {
+            lazy_csv_reader = lazy_csv_reader.with_schema(schema.clone());
+        }
+        if let Some(separator) = separator {
+            lazy_csv_reader = lazy_csv_reader.with_delimiter(separator);
+        }
+        if let Some(has_header) = has_header {
+            lazy_csv_reader = lazy_csv_reader.has_header(has_header);
+        }
+        if let Some(skip_rows) = skip_rows {
+            lazy_csv_reader = lazy_csv_reader.with_skip_rows(skip_rows);
+        }
+        // As with the eager reader, we only override Polars' default of double
+        // quotes when a quote character is explicitly passed.
+        if let Some(quote_char) = quote_char {
+            lazy_csv_reader = lazy_csv_reader.with_quote_char(Some(quote_char));
+        }
+        if let Some(infer_schema_length) = infer_schema_length {
+            lazy_csv_reader = lazy_csv_reader.with_infer_schema_length(Some(infer_schema_length));
+        }
+        if let Some(ignore_errors) = ignore_errors {
+            lazy_csv_reader = lazy_csv_reader.with_ignore_errors(ignore_errors);
+        }
+        if let Some(try_parse_dates) = try_parse_dates {
+            lazy_csv_reader = lazy_csv_reader.with_try_parse_dates(try_parse_dates);
+        }
+        if let Some(encoding) = encoding {
+            lazy_csv_reader = lazy_csv_reader.with_encoding(encoding);
+        }
+        if let Some(low_memory) = low_memory {
+            lazy_csv_reader = lazy_csv_reader.low_memory(low_memory);
+        }
+        if let Some(cache) = cache {
+            lazy_csv_reader = lazy_csv_reader.with_cache(cache);
+        }
+
+        lazy_csv_reader
+            .finish()
+            .and_then(|lazy_frame| parse_decimal_columns_lazy(lazy_frame, &decimals))
+    };
 
-    LazyCsvReader::new(path)
-        .finish()
-        .map(Abstract)
-        .map_err(|err| err.to_string())
-        .to_ocaml(cr)
+    hive::scan_files(
+        paths,
+        hive_partitioning.unwrap_or(false),
+        hive_schema,
+        n_rows,
+        row_count,
+        scan,
+    )
+    .map(Abstract)
+    .map_err(|err| err.to_string())
+    .to_ocaml(cr)
 }
 
 // TODO: properly return error type instead of a string
-#[ocaml_interop_export]
+#[ocaml_interop_export(raise_on_err)]
 fn rust_lazy_frame_scan_parquet(
+    cr: &mut &mut OCamlRuntime,
+    paths: OCamlRef<OCamlList<String>>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    row_count: OCamlRef<Option<(String, OCamlInt)>>,
+    parallel: OCamlRef<Option<ParallelStrategy>>,
+    low_memory: OCamlRef<Option<bool>>,
+    rechunk: OCamlRef<Option<bool>>,
+    cache: OCamlRef<Option<bool>>,
+    hive_partitioning: OCamlRef<Option<bool>>,
+    hive_schema: OCamlRef<Option<DynBox<Schema>>>,
+) -> OCaml<Result<DynBox<LazyFrame>, String>> {
+    let paths: Vec<String> = paths.to_rust(cr);
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let row_count = row_count
+        .to_rust::<Option<PolarsRowCount>>(cr)
+        .map(|PolarsRowCount(row_count)| row_count);
+    let parallel = parallel
+        .to_rust::<Option<PolarsParallelStrategy>>(cr)
+        .map(|PolarsParallelStrategy(parallel)| parallel);
+    let low_memory: Option<bool> = low_memory.to_rust(cr);
+    let rechunk: Option<bool> = rechunk.to_rust(cr);
+    let cache: Option<bool> = cache.to_rust(cr);
+    let hive_partitioning: Option<bool> = hive_partitioning.to_rust(cr);
+    let hive_schema = hive_schema
+        .to_rust::<Option<Abstract<Schema>>>(cr)
+        .map(|Abstract(hive_schema)| hive_schema);
+
+    let scan = move |path: &Path, n_rows: Option<usize>, row_count: Option<RowCount>| {
+        let defaults = ScanArgsParquet::default();
+        let args = ScanArgsParquet {
+            n_rows,
+            row_count,
+            parallel: parallel.unwrap_or(defaults.parallel),
+            low_memory: low_memory.unwrap_or(defaults.low_memory),
+            rechunk: rechunk.unwrap_or(defaults.rechunk),
+            cache: cache.unwrap_or(defaults.cache),
+            ..defaults
+        };
+        LazyFrame::scan_parquet(path, args)
+    };
+
+    hive::scan_files(
+        paths,
+        hive_partitioning.unwrap_or(false),
+        hive_schema,
+        n_rows,
+        row_count,
+        scan,
+    )
+    .map(Abstract)
+    .map_err(|err| err.to_string())
+    .to_ocaml(cr)
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_lazy_frame_scan_ipc(
     cr: &mut &mut OCamlRuntime,
     path: OCamlRef<String>,
+    n_rows: OCamlRef<Option<OCamlInt>>,
+    row_count: OCamlRef<Option<(String, OCamlInt)>>,
+    memory_map: OCamlRef<Option<bool>>,
+    rechunk: OCamlRef<Option<bool>>,
+    cache: OCamlRef<Option<bool>>,
 ) -> OCaml<Result<DynBox<LazyFrame>, String>> {
     let path: String = path.to_rust(cr);
     let path: &Path = Path::new(&path);
+    let n_rows = n_rows
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let row_count = row_count
+        .to_rust::<Option<PolarsRowCount>>(cr)
+        .map(|PolarsRowCount(row_count)| row_count);
+    let memory_map: Option<bool> = memory_map.to_rust(cr);
+    let rechunk: Option<bool> = rechunk.to_rust(cr);
+    let cache: Option<bool> = cache.to_rust(cr);
+
+    let defaults = ScanArgsIpc::default();
+    let args = ScanArgsIpc {
+        n_rows,
+        row_count,
+        memmap: memory_map.unwrap_or(defaults.memmap),
+        rechunk: rechunk.unwrap_or(defaults.rechunk),
+        cache: cache.unwrap_or(defaults.cache),
+    };
 
-    LazyFrame::scan_parquet(path, Default::default())
+    LazyFrame::scan_ipc(path, args)
         .map(Abstract)
         .map_err(|err| err.to_string())
         .to_ocaml(cr)
@@ -176,6 +385,126 @@ fn rust_lazy_frame_fetch(
     })
 }
 
+// TODO: Polars 0.32.1 can only sink to Parquet and IPC files (`FileType` has no
+// CSV variant), so `sink_csv` will have to wait until we upgrade.
+#[ocaml_interop_export(raise_on_err)]
+fn rust_lazy_frame_sink_parquet(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    path: OCamlRef<String>,
+    compression: OCamlRef<ParquetCompression>,
+    statistics: OCamlRef<bool>,
+    row_group_size: OCamlRef<Option<OCamlInt>>,
+    data_page_size: OCamlRef<Option<OCamlInt>>,
+    maintain_order: OCamlRef<bool>,
+) -> OCaml<Result<(), String>> {
+    let Abstract(lazy_frame) = lazy_frame.to_rust(cr);
+    let path: String = path.to_rust(cr);
+    let PolarsParquetCompression(compression) = compression.to_rust(cr);
+    let statistics: bool = statistics.to_rust(cr);
+    let row_group_size = row_group_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let data_pagesize_limit = data_page_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let maintain_order: bool = maintain_order.to_rust(cr);
+
+    let options = ParquetWriteOptions {
+        compression,
+        statistics,
+        row_group_size,
+        data_pagesize_limit,
+        maintain_order,
+    };
+
+    cr.releasing_runtime(|| lazy_frame.sink_parquet(path.into(), options))
+        .map_err(|err| err.to_string())
+        .to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_lazy_frame_sink_ipc(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    path: OCamlRef<String>,
+    compression: OCamlRef<Option<IpcCompression>>,
+    maintain_order: OCamlRef<bool>,
+) -> OCaml<Result<(), String>> {
+    let Abstract(lazy_frame) = lazy_frame.to_rust(cr);
+    let path: String = path.to_rust(cr);
+    let compression = compression
+        .to_rust::<Option<PolarsIpcCompression>>(cr)
+        .map(|PolarsIpcCompression(compression)| compression);
+    let maintain_order: bool = maintain_order.to_rust(cr);
+
+    let options = IpcWriterOptions {
+        compression,
+        maintain_order,
+    };
+
+    cr.releasing_runtime(|| lazy_frame.sink_ipc(path.into(), options))
+        .map_err(|err| err.to_string())
+        .to_ocaml(cr)
+}
+
+// Polars 0.32.1 has no partitioned sink, so the frame is collected in full
+// before being split up and written.
+#[ocaml_interop_export(raise_on_err)]
+fn rust_lazy_frame_write_partitioned(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    root: OCamlRef<String>,
+    partition_by: OCamlRef<OCamlList<String>>,
+    format: OCamlRef<FileFormat>,
+    compression: OCamlRef<ParquetCompression>,
+    statistics: OCamlRef<bool>,
+    row_group_size: OCamlRef<Option<OCamlInt>>,
+    data_page_size: OCamlRef<Option<OCamlInt>>,
+) -> OCaml<Result<OCamlList<(String, OCamlInt)>, String>> {
+    let Abstract(lazy_frame) = lazy_frame.to_rust(cr);
+    let root: String = root.to_rust(cr);
+    let partition_by: Vec<String> = partition_by.to_rust(cr);
+    let format: FileFormat = format.to_rust(cr);
+    let PolarsParquetCompression(compression) = compression.to_rust(cr);
+    let statistics: bool = statistics.to_rust(cr);
+    let row_group_size = row_group_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+    let data_pagesize_limit = data_page_size
+        .to_rust::<Coerce<_, Option<i64>, Option<usize>>>(cr)
+        .get()?;
+
+    let parquet_options = hive::ParquetOptions {
+        compression,
+        statistics,
+        row_group_size,
+        data_pagesize_limit,
+    };
+
+    cr.releasing_runtime(|| {
+        lazy_frame
+            .collect()
+            .map_err(|err| err.to_string())
+            .and_then(|data_frame| {
+                hive::write_partitioned(
+                    &data_frame,
+                    Path::new(&root),
+                    &partition_by,
+                    format,
+                    &parquet_options,
+                )
+            })
+    })
+    .map(|manifest| {
+        manifest
+            .into_iter()
+            .map(|(path, rows)| (path, OCamlIntable(rows)))
+            .collect::<Vec<_>>()
+    })
+    .to_ocaml(cr)
+}
+
 #[ocaml_interop_export]
 fn rust_lazy_frame_filter(
     cr: &mut &mut OCamlRuntime,
@@ -437,6 +766,125 @@ fn rust_lazy_frame_explode(
     dyn_box!(cr, |lazy_frame| lazy_frame.explode(&columns))
 }
 
+#[ocaml_interop_export]
+fn rust_lazy_frame_unique(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    subset: OCamlRef<Option<OCamlList<String>>>,
+    keep: OCamlRef<UniqueKeepStrategy>,
+    maintain_order: OCamlRef<bool>,
+) -> OCaml<DynBox<LazyFrame>> {
+    let subset: Option<Vec<String>> = subset.to_rust(cr);
+    let PolarsUniqueKeepStrategy(keep) = keep.to_rust(cr);
+    let maintain_order: bool = maintain_order.to_rust(cr);
+
+    dyn_box!(cr, |lazy_frame| {
+        if maintain_order {
+            lazy_frame.unique_stable(subset, keep)
+        } else {
+            lazy_frame.unique(subset, keep)
+        }
+    })
+}
+
+#[ocaml_interop_export]
+fn rust_lazy_frame_drop_nulls(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    subset: OCamlRef<Option<OCamlList<String>>>,
+) -> OCaml<DynBox<LazyFrame>> {
+    let subset: Option<Vec<Expr>> = subset
+        .to_rust::<Option<Vec<String>>>(cr)
+        .map(|subset| subset.iter().map(|name| col(name)).collect());
+
+    dyn_box!(cr, |lazy_frame| lazy_frame.drop_nulls(subset))
+}
+
+#[ocaml_interop_export]
+fn rust_lazy_frame_drop(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    names: OCamlRef<OCamlList<String>>,
+) -> OCaml<DynBox<LazyFrame>> {
+    let names: Vec<String> = names.to_rust(cr);
+
+    dyn_box!(cr, |lazy_frame| lazy_frame.drop_columns(names))
+}
+
+#[ocaml_interop_export]
+fn rust_lazy_frame_rename(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    mapping: OCamlRef<OCamlList<(String, String)>>,
+) -> OCaml<DynBox<LazyFrame>> {
+    let (existing, new): (Vec<String>, Vec<String>) = mapping
+        .to_rust::<Vec<(String, String)>>(cr)
+        .into_iter()
+        .unzip();
+
+    dyn_box!(cr, |lazy_frame| lazy_frame.rename(existing, new))
+}
+
+// LazyFrames only hold expressions, so a column is inserted or replaced at an
+// index by selecting every column, with the new expression in place.
+fn with_column_at(
+    lazy_frame: LazyFrame,
+    index: usize,
+    expr: Expr,
+    replace: bool,
+) -> PolarsResult<LazyFrame> {
+    let mut exprs: Vec<Expr> = lazy_frame
+        .schema()?
+        .iter_names()
+        .map(|name| col(name))
+        .collect();
+    let width = exprs.len();
+    if replace && index < width {
+        exprs[index] = expr;
+    } else if !replace && index <= width {
+        exprs.insert(index, expr);
+    } else {
+        polars_bail!(
+            ComputeError:
+            "unable to {} at index {}, the dataframe has only {} columns",
+            if replace { "replace" } else { "insert" },
+            index,
+            width
+        );
+    }
+    Ok(lazy_frame.select(exprs))
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_lazy_frame_replace_column(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    index: OCamlRef<OCamlInt>,
+    expr: OCamlRef<DynBox<Expr>>,
+) -> OCaml<Result<DynBox<LazyFrame>, String>> {
+    let index = index.to_rust::<Coerce<_, i64, usize>>(cr).get()?;
+    let Abstract(expr) = expr.to_rust(cr);
+
+    dyn_box_result!(cr, |lazy_frame| with_column_at(
+        lazy_frame, index, expr, true
+    ))
+}
+
+#[ocaml_interop_export(raise_on_err)]
+fn rust_lazy_frame_insert_column(
+    cr: &mut &mut OCamlRuntime,
+    lazy_frame: OCamlRef<DynBox<LazyFrame>>,
+    index: OCamlRef<OCamlInt>,
+    expr: OCamlRef<DynBox<Expr>>,
+) -> OCaml<Result<DynBox<LazyFrame>, String>> {
+    let index = index.to_rust::<Coerce<_, i64, usize>>(cr).get()?;
+    let Abstract(expr) = expr.to_rust(cr);
+
+    dyn_box_result!(cr, |lazy_frame| with_column_at(
+        lazy_frame, index, expr, false
+    ))
+}
+
 #[ocaml_interop_export]
 fn rust_lazy_frame_with_streaming(
     cr: &mut &mut OCamlRuntime,
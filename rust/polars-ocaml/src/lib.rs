@@ -1,7 +1,10 @@
+mod arrow_ffi;
 mod data_frame;
 mod expr;
+mod hive;
 mod lazy_frame;
 mod misc;
+mod rows;
 mod series;
 mod sql_context;
 mod utils;
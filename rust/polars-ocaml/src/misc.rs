@@ -1,3 +1,4 @@
+use crate::arrow_ffi;
 use crate::utils::PolarsDataType;
 use crate::utils::*;
 use chrono::naive::{NaiveDate, NaiveDateTime};
@@ -21,6 +22,16 @@ fn rust_naive_date(
         .to_ocaml(cr)
 }
 
+#[ocaml_interop_export]
+fn rust_naive_date_to_string(
+    cr: &mut &mut OCamlRuntime,
+    date: OCamlRef<DynBox<NaiveDate>>,
+) -> OCaml<String> {
+    let Abstract(date) = date.to_rust(cr);
+
+    date.to_string().to_ocaml(cr)
+}
+
 #[ocaml_interop_export(raise_on_err)]
 fn rust_naive_date_to_naive_datetime(
     cr: &mut &mut OCamlRuntime,
@@ -113,6 +124,44 @@ fn rust_schema_to_fields(
     fields.to_ocaml(cr)
 }
 
+#[ocaml_interop_export]
+fn rust_enable_string_cache(cr: &mut &mut OCamlRuntime, toggle: OCamlRef<bool>) -> OCaml<()> {
+    let toggle: bool = toggle.to_rust(cr);
+
+    polars::enable_string_cache(toggle);
+
+    OCaml::unit()
+}
+
+#[ocaml_interop_export]
+fn rust_using_string_cache(cr: &mut &mut OCamlRuntime, unit: OCamlRef<()>) -> OCaml<bool> {
+    let () = unit.to_rust(cr);
+
+    polars::using_string_cache().to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_arrow_c_allocate(
+    cr: &mut &mut OCamlRuntime,
+    kind: OCamlRef<ArrowCStruct>,
+) -> OCaml<OCamlInt> {
+    let kind: ArrowCStruct = kind.to_rust(cr);
+
+    arrow_ffi::allocate(kind).to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_arrow_c_free(
+    cr: &mut &mut OCamlRuntime,
+    kind: OCamlRef<ArrowCStruct>,
+    address: OCamlRef<OCamlInt>,
+) -> OCaml<Result<(), String>> {
+    let kind: ArrowCStruct = kind.to_rust(cr);
+    let address: i64 = address.to_rust(cr);
+
+    unsafe { arrow_ffi::free(kind, address) }.to_ocaml(cr)
+}
+
 #[ocaml_interop_export]
 fn rust_test_panic(cr: &mut &mut OCamlRuntime, error_message: OCamlRef<String>) -> OCaml<()> {
     let error_message: String = error_message.to_rust(cr);
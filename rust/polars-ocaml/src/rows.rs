@@ -0,0 +1,193 @@
+// Support for building DataFrames out of rows of AnyValues and reading them
+// back a row at a time, for code which naturally works with rows. This is a lot
+// slower than working column by column.
+use crate::series::list_to_array;
+use crate::utils::{nanoseconds_per_unit, PolarsAnyValue};
+use polars::frame::row::{rows_to_schema_first_non_null, Row};
+use polars::prelude::*;
+
+fn convert_time_unit(value: i64, from: TimeUnit, to: TimeUnit) -> Option<i64> {
+    let (from, to) = (nanoseconds_per_unit(from), nanoseconds_per_unit(to));
+    if from >= to {
+        value.checked_mul(from / to)
+    } else {
+        Some(value / (to / from))
+    }
+}
+
+// Polars reads the physical value of datetimes and durations without looking
+// at their time unit, so we first convert them to the time unit of the column.
+fn with_time_unit<'a>(value: &AnyValue<'a>, data_type: &DataType) -> Result<AnyValue<'a>, String> {
+    let out_of_range =
+        |value: i64, from: TimeUnit| format!("{}{} is out of range for {}", value, from, data_type);
+    match (value, data_type) {
+        (AnyValue::Datetime(value, from, timezone), DataType::Datetime(to, _)) => {
+            convert_time_unit(*value, *from, *to)
+                .map(|value| AnyValue::Datetime(value, *to, timezone))
+                .ok_or_else(|| out_of_range(*value, *from))
+        }
+        (AnyValue::Duration(value, from), DataType::Duration(to)) => {
+            convert_time_unit(*value, *from, *to)
+                .map(|value| AnyValue::Duration(value, *to))
+                .ok_or_else(|| out_of_range(*value, *from))
+        }
+        (value, _) => Ok(value.clone()),
+    }
+}
+
+// Whether Polars represents `value` as a null. Struct columns have no validity
+// of their own: a struct is null when all of its fields are.
+fn is_null_value(value: &AnyValue) -> bool {
+    match value {
+        AnyValue::Null => true,
+        AnyValue::StructOwned(payload) => payload.0.iter().all(is_null_value),
+        _ => false,
+    }
+}
+
+// Builds a column of the given data type. Polars silently turns values it
+// can't convert into nulls (and only reports an error for some data types,
+// without saying which value caused it), so we look for the first such value
+// to report it instead.
+fn coerce_column(name: &str, values: &[AnyValue], data_type: &DataType) -> Result<Series, String> {
+    if let DataType::Array(inner, width) = data_type {
+        let list = coerce_column(name, values, &DataType::List(inner.clone()))?;
+        return list
+            .list()
+            .and_then(|list| list_to_array(list, *width))
+            .map_err(|err| format!("column {}: {}", name, err));
+    }
+
+    let values: Vec<AnyValue> = values
+        .iter()
+        .enumerate()
+        .map(|(row, value)| {
+            with_time_unit(value, data_type)
+                .map_err(|err| format!("row {}, column {}: {}", row, name, err))
+        })
+        .collect::<Result<_, _>>()?;
+    let series = Series::from_any_values_and_dtype(name, &values, data_type, true);
+
+    let failed_row = match &series {
+        Ok(series) => values
+            .iter()
+            .zip(series.is_null().into_iter())
+            .position(|(value, is_null)| !is_null_value(value) && is_null.unwrap_or(true)),
+        Err(_) => values.iter().position(|value| {
+            Series::from_any_values_and_dtype(name, std::slice::from_ref(value), data_type, true)
+                .is_err()
+        }),
+    };
+
+    match (series, failed_row) {
+        (Ok(series), None) => Ok(series),
+        (Err(err), None) => Err(format!("column {}: {}", name, err)),
+        (_, Some(row)) => Err(format!(
+            "row {}, column {}: cannot convert {} of type {} to {}",
+            row,
+            name,
+            values[row],
+            values[row].dtype(),
+            data_type
+        )),
+    }
+}
+
+// Builds a DataFrame with the given schema, or with a schema inferred from the
+// first non-null value of each column (like `DataFrame::from_rows`, but looking
+// at every row). Columns of an inferred schema are named `column_0`,
+// `column_1`, etc. Values which couldn't be converted from OCaml are reported
+// with their row and column.
+pub fn data_frame_from_rows(
+    rows: Vec<Vec<Result<AnyValue<'static>, String>>>,
+    schema: Option<Schema>,
+) -> Result<DataFrame, String> {
+    let width = match (&schema, rows.first()) {
+        (Some(schema), _) => schema.len(),
+        (None, Some(row)) => row.len(),
+        (None, None) => return Err("cannot infer a schema without any rows".to_string()),
+    };
+    if let Some(row) = rows.iter().position(|row| row.len() != width) {
+        return Err(format!(
+            "row {} has {} values, expected {}",
+            row,
+            rows[row].len(),
+            width
+        ));
+    }
+
+    let column_name = |column: usize| match schema.as_ref().and_then(|s| s.get_at_index(column)) {
+        Some((name, _)) => name.to_string(),
+        None => format!("column_{}", column),
+    };
+    let rows = rows
+        .into_iter()
+        .enumerate()
+        .map(|(row, values)| {
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(column, value)| {
+                    value.map_err(|err| {
+                        format!("row {}, column {}: {}", row, column_name(column), err)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(Row::new)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let schema = schema.unwrap_or_else(|| rows_to_schema_first_non_null(&rows, None));
+    let columns = schema
+        .iter()
+        .enumerate()
+        .map(|(i, (name, data_type))| {
+            let values: Vec<AnyValue> = rows.iter().map(|row| row.0[i].clone()).collect();
+            coerce_column(name, &values, data_type)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    DataFrame::new(columns).map_err(|err| err.to_string())
+}
+
+fn check_rows_in_bounds(
+    data_frame: &DataFrame,
+    offset: usize,
+    length: usize,
+) -> Result<(), String> {
+    let height = data_frame.height();
+    match offset.checked_add(length) {
+        Some(end) if end <= height => Ok(()),
+        _ => Err(format!(
+            "rows {}..{} are out of bounds for a DataFrame with {} rows",
+            offset,
+            offset.saturating_add(length),
+            height
+        )),
+    }
+}
+
+// The rows `offset..offset + length` of the DataFrame, all of which must exist.
+pub fn rows_slice(
+    data_frame: &DataFrame,
+    offset: usize,
+    length: usize,
+) -> Result<DataFrame, String> {
+    check_rows_in_bounds(data_frame, offset, length)?;
+    Ok(data_frame.slice(offset as i64, length))
+}
+
+pub fn any_value_rows(
+    data_frame: &DataFrame,
+    offset: usize,
+    length: usize,
+) -> Result<Vec<Vec<PolarsAnyValue>>, String> {
+    check_rows_in_bounds(data_frame, offset, length)?;
+    (offset..offset + length)
+        .map(|i| {
+            data_frame
+                .get_row(i)
+                .map_err(|err| err.to_string())
+                .and_then(|Row(values)| values.into_iter().map(PolarsAnyValue::new).collect())
+        })
+        .collect()
+}
@@ -1,9 +1,13 @@
+use crate::arrow_ffi;
 use crate::utils::*;
 use chrono::naive::{NaiveDate, NaiveDateTime};
 use ocaml_interop::{
-    BoxRoot, DynBox, OCaml, OCamlBytes, OCamlFloat, OCamlInt, OCamlList, OCamlRef, OCamlRuntime,
-    ToOCaml,
+    bigarray::Array1, BoxRoot, DynBox, OCaml, OCamlBytes, OCamlFloat, OCamlInt, OCamlList,
+    OCamlRef, OCamlRuntime, ToOCaml,
 };
+use polars::export::arrow::array::FixedSizeListArray;
+use polars::export::arrow::bitmap::MutableBitmap;
+use polars::export::arrow::temporal_conversions::date32_to_date;
 use polars::prelude::prelude::*;
 use polars::prelude::*;
 use polars_ocaml_macros::ocaml_interop_export;
@@ -71,6 +75,36 @@ pub fn series_new(
         }};
     }
 
+    // Datetime, Duration and Time series are backed by Int64 series, so we
+    // convert each value to its physical representation and then attach the
+    // logical data type.
+    macro_rules! create_temporal_series {
+        ($ocaml_type:ty, $rust_type:ty, $to_physical:expr, $into_logical:expr) => {{
+            let to_physical = $to_physical;
+            let values = if are_values_options {
+                values
+                    .into_iter()
+                    .map(|v| {
+                        v.interpret::<Option<$ocaml_type>>(cr)
+                            .to_rust::<Option<$rust_type>>()
+                            .map(&to_physical)
+                            .transpose()
+                    })
+                    .collect::<Result<Vec<Option<i64>>, String>>()?
+            } else {
+                values
+                    .into_iter()
+                    .map(|v| {
+                        to_physical(v.interpret::<$ocaml_type>(cr).to_rust::<$rust_type>())
+                            .map(Some)
+                    })
+                    .collect::<Result<Vec<Option<i64>>, String>>()?
+            };
+            let physical = Int64Chunked::from_iter_options(name, values.into_iter());
+            Ok($into_logical(physical).into_series())
+        }};
+    }
+
     match data_type {
         GADTDataType::Boolean => create_series!(bool, bool),
         GADTDataType::UInt8 => create_int_series!(u8),
@@ -103,6 +137,128 @@ pub fn series_new(
         GADTDataType::Float64 => create_series!(OCamlFloat, f64),
         GADTDataType::Utf8 => create_series!(String, String),
         GADTDataType::Binary => create_series!(OCamlBytes, Vec<u8>),
+        GADTDataType::Date => {
+            if are_values_options {
+                let values: Vec<Option<NaiveDate>> = values
+                    .into_iter()
+                    .map(|v| {
+                        v.interpret::<Option<DynBox<NaiveDate>>>(cr)
+                            .to_rust::<Option<Abstract<NaiveDate>>>()
+                            .map(|Abstract(date)| date)
+                    })
+                    .collect();
+                Ok(Series::new(name, values))
+            } else {
+                let values: Vec<NaiveDate> = values
+                    .into_iter()
+                    .map(|v| {
+                        let Abstract(date) = v.interpret::<DynBox<NaiveDate>>(cr).to_rust();
+                        date
+                    })
+                    .collect();
+                Ok(Series::new(name, values))
+            }
+        }
+        GADTDataType::Datetime(timeunit, timezone) => create_temporal_series!(
+            DynBox<NaiveDateTime>,
+            Abstract<NaiveDateTime>,
+            |Abstract(datetime)| naive_datetime_to_timestamp(datetime, *timeunit),
+            |physical: Int64Chunked| physical.into_datetime(*timeunit, timezone.clone())
+        ),
+        // Durations are truncated to the precision of the time unit.
+        GADTDataType::Duration(timeunit) => create_temporal_series!(
+            OCamlInt63,
+            OCamlInt63,
+            |OCamlInt63(nanoseconds)| Ok(nanoseconds / nanoseconds_per_unit(*timeunit)),
+            |physical: Int64Chunked| physical.into_duration(*timeunit)
+        ),
+        GADTDataType::Time => create_temporal_series!(
+            OCamlInt63,
+            OCamlInt63,
+            |OCamlInt63(nanoseconds)| time_of_day_to_nanoseconds(nanoseconds),
+            |physical: Int64Chunked| physical.into_time()
+        ),
+        // Decimal values are scaled integers, see decimal_to_scaled_int.
+        GADTDataType::Decimal(precision, scale) => {
+            let values: Vec<Option<i128>> = if are_values_options {
+                values
+                    .into_iter()
+                    .map(|v| {
+                        v.interpret::<Option<OCamlInt>>(cr)
+                            .to_rust::<Option<i64>>()
+                            .map(i128::from)
+                    })
+                    .collect()
+            } else {
+                values
+                    .into_iter()
+                    .map(|v| Some(i128::from(v.interpret::<OCamlInt>(cr).to_rust::<i64>())))
+                    .collect()
+            };
+            Int128Chunked::from_iter_options(name, values.into_iter())
+                .into_decimal(*precision, *scale)
+                .map(|ca| ca.into_series())
+                .map_err(|err| err.to_string())
+        }
+        GADTDataType::Struct(fields) => {
+            // Struct values are nested pairs (field1, (field2, (..., ()))).
+            // Polars 0.32's struct arrays have no validity of their own, so
+            // null values become rows where every field is null.
+            let rows: Vec<Option<DummyBoxRoot>> = if are_values_options {
+                values
+                    .into_iter()
+                    .map(|v| v.interpret::<Option<DummyBoxRoot>>(cr).to_rust())
+                    .collect()
+            } else {
+                values.into_iter().map(Some).collect()
+            };
+            let mut non_null_index: IdxSize = 0;
+            let indices = IdxCa::from_iter_options(
+                "",
+                rows.iter().map(|row| {
+                    row.as_ref().map(|_| {
+                        non_null_index += 1;
+                        non_null_index - 1
+                    })
+                }),
+            );
+
+            let mut rest: Vec<DummyBoxRoot> = rows.into_iter().flatten().collect();
+            let mut field_series = Vec::with_capacity(fields.len());
+            for (field_name, field_data_type) in fields {
+                let (values, tails): (Vec<DummyBoxRoot>, Vec<DummyBoxRoot>) = rest
+                    .into_iter()
+                    .map(|v| {
+                        v.interpret::<(DummyBoxRoot, DummyBoxRoot)>(cr)
+                            .to_rust::<(DummyBoxRoot, DummyBoxRoot)>()
+                    })
+                    .unzip();
+                let series = series_new(cr, field_data_type, field_name, values, false)?;
+                let series = if are_values_options {
+                    series.take(&indices).map_err(|err| err.to_string())?
+                } else {
+                    series
+                };
+                field_series.push(series);
+                rest = tails;
+            }
+
+            StructChunked::new(name, &field_series)
+                .map(|ca| ca.into_series())
+                .map_err(|err| err.to_string())
+        }
+        GADTDataType::Array(data_type, width) => {
+            let list = series_new(
+                cr,
+                &GADTDataType::List(data_type.clone()),
+                name,
+                values,
+                are_values_options,
+            )?;
+            list.list()
+                .and_then(|list| list_to_array(list, *width))
+                .map_err(|err| err.to_string())
+        }
         GADTDataType::List(data_type) => {
             // Series creation doesn't work for empty lists and use of
             // `Series::new_empty` is suggested instead.
@@ -149,6 +305,39 @@ pub fn series_new(
     }
 }
 
+// Converts a List series whose elements all have `width` values into an Array
+// series. Polars 0.32.1 can only cast lists without nulls to arrays, so we build
+// the underlying FixedSizeListArray ourselves, storing `width` null values for
+// every null element.
+pub fn list_to_array(list: &ListChunked, width: usize) -> PolarsResult<Series> {
+    polars_ensure!(width > 0, ComputeError: "Array width must be positive");
+
+    let inner_dtype = list.inner_dtype();
+    let mut values = Series::new_empty("", &inner_dtype);
+    let mut validity = MutableBitmap::with_capacity(list.len());
+    for element in list {
+        validity.push(element.is_some());
+        match element {
+            Some(element) => {
+                polars_ensure!(
+                    element.len() == width,
+                    ComputeError: "expected arrays of width {}, got {} values", width, element.len()
+                );
+                values.append(&element)?;
+            }
+            None => {
+                values.append(&Series::full_null("", width, &inner_dtype))?;
+            }
+        }
+    }
+
+    let values = values.rechunk().to_arrow(0);
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+    let validity = (list.null_count() > 0).then(|| validity.into());
+    let array = FixedSizeListArray::new(data_type, values, validity);
+    Series::try_from((list.name(), Box::new(array) as ArrayRef))
+}
+
 #[ocaml_interop_export(raise_on_err)]
 fn rust_series_new(
     cr: &mut &mut OCamlRuntime,
@@ -190,9 +379,19 @@ fn rust_series_new_array(
 ) -> OCaml<DynBox<PolarsSeries>> {
     let name: String = name.to_rust(cr);
     let data_type: GADTDataType = data_type.to_rust(cr);
-    let values: Vec<DummyBoxRoot> = values.to_rust(cr);
-
-    let series = series_new(cr, &data_type, &name, values, false)?;
+    let values: UniformArrayValues<DummyBoxRoot> = values.to_rust(cr);
+
+    let series = match (values, &data_type) {
+        (UniformArrayValues::Boxed(values), _) => series_new(cr, &data_type, &name, values, false)?,
+        (UniformArrayValues::Floats(values), GADTDataType::Float64) => Series::new(&name, values),
+        (UniformArrayValues::Floats(values), GADTDataType::Float32) => Series::new(
+            &name,
+            values.into_iter().map(|f64| f64 as f32).collect::<Vec<_>>(),
+        ),
+        // Only arrays with elements of type float can be flat float arrays,
+        // and Float32 and Float64 are the only such data types.
+        (UniformArrayValues::Floats(_), _) => unreachable!(),
+    };
 
     OCaml::box_value(cr, Rc::new(RefCell::new(series)))
 }
@@ -214,57 +413,84 @@ fn rust_series_new_option_array(
 }
 
 #[ocaml_interop_export]
-fn rust_series_new_datetime(
+fn rust_series_new_float_array(
     cr: &mut &mut OCamlRuntime,
     name: OCamlRef<String>,
-    values: OCamlRef<OCamlList<DynBox<NaiveDateTime>>>,
+    values: OCamlRef<OCamlFloatArray>,
 ) -> OCaml<DynBox<PolarsSeries>> {
     let name: String = name.to_rust(cr);
-    let values = unwrap_abstract_vec(values.to_rust(cr));
-    OCaml::box_value(cr, Rc::new(RefCell::new(Series::new(&name, values))))
-}
+    let values: Vec<f64> = values.to_rust(cr);
 
-#[ocaml_interop_export]
-fn rust_series_new_datetime_option(
-    cr: &mut &mut OCamlRuntime,
-    name: OCamlRef<String>,
-    values: OCamlRef<OCamlList<Option<DynBox<NaiveDateTime>>>>,
-) -> OCaml<DynBox<PolarsSeries>> {
-    let name: String = name.to_rust(cr);
-    let values: Vec<Option<NaiveDateTime>> = values
-        .to_rust::<Vec<Option<_>>>(cr)
-        .into_iter()
-        .map(|o| o.map(|Abstract(v)| v))
-        .collect();
-
-    OCaml::box_value(cr, Rc::new(RefCell::new(Series::new(&name, values))))
-}
+    let series = Float64Chunked::from_vec(&name, values).into_series();
 
-#[ocaml_interop_export]
-fn rust_series_new_date(
-    cr: &mut &mut OCamlRuntime,
-    name: OCamlRef<String>,
-    values: OCamlRef<OCamlList<DynBox<NaiveDate>>>,
-) -> OCaml<DynBox<PolarsSeries>> {
-    let name: String = name.to_rust(cr);
-    let values = unwrap_abstract_vec(values.to_rust(cr));
-    OCaml::box_value(cr, Rc::new(RefCell::new(Series::new(&name, values))))
+    OCaml::box_value(cr, Rc::new(RefCell::new(series)))
 }
 
+// Copies a Bigarray of any integer or float kind into a single-chunk series,
+// without going through OCaml values one element at a time. As with
+// rust_series_to_bigarray, validity is a mask with 1 for valid values and 0 for
+// nulls.
 #[ocaml_interop_export]
-fn rust_series_new_date_option(
+fn rust_series_new_bigarray(
     cr: &mut &mut OCamlRuntime,
     name: OCamlRef<String>,
-    values: OCamlRef<OCamlList<Option<DynBox<NaiveDate>>>>,
-) -> OCaml<DynBox<PolarsSeries>> {
+    kind: OCamlRef<OCamlInt>,
+    values: OCamlRef<DummyBoxRoot>,
+    validity: OCamlRef<Option<Array1<u8>>>,
+) -> OCaml<Result<DynBox<PolarsSeries>, String>> {
     let name: String = name.to_rust(cr);
-    let values: Vec<Option<NaiveDate>> = values
-        .to_rust::<Vec<Option<_>>>(cr)
-        .into_iter()
-        .map(|o| o.map(|Abstract(v)| v))
-        .collect();
+    let kind: i64 = kind.to_rust(cr);
+    let values: DummyBoxRoot = values.to_rust(cr);
+    let validity = validity
+        .to_rust::<Option<OCamlBigstring>>(cr)
+        .map(|OCamlBigstring(validity)| validity);
+
+    macro_rules! from_bigarray {
+        ($polars_type:ty, $rust_type:ty) => {{
+            let values = values
+                .interpret::<Array1<$rust_type>>(cr)
+                .as_slice()
+                .to_vec();
+            match validity {
+                Some(validity) if validity.len() != values.len() => Err(format!(
+                    "Validity mask has length {}, expected {}",
+                    validity.len(),
+                    values.len()
+                )),
+                validity => {
+                    let validity = validity
+                        .map(|validity| validity.into_iter().map(|valid| valid != 0).collect());
+                    Ok(
+                        ChunkedArray::<$polars_type>::new_from_owned_with_null_bitmap(
+                            &name, values, validity,
+                        )
+                        .into_series(),
+                    )
+                }
+            }
+        }};
+    }
 
-    OCaml::box_value(cr, Rc::new(RefCell::new(Series::new(&name, values))))
+    let series = match kind {
+        // OCaml's int and nativeint kinds are both stored as untagged native
+        // integers, which are 64 bits wide on all platforms we support.
+        8 | 9 => from_bigarray!(Int64Type, i64),
+        kind => bigarray_kind_data_type(kind).and_then(|data_type| match data_type {
+            DataType::Float32 => from_bigarray!(Float32Type, f32),
+            DataType::Float64 => from_bigarray!(Float64Type, f64),
+            DataType::Int8 => from_bigarray!(Int8Type, i8),
+            DataType::UInt8 => from_bigarray!(UInt8Type, u8),
+            DataType::Int16 => from_bigarray!(Int16Type, i16),
+            DataType::UInt16 => from_bigarray!(UInt16Type, u16),
+            DataType::Int32 => from_bigarray!(Int32Type, i32),
+            DataType::Int64 => from_bigarray!(Int64Type, i64),
+            _ => unreachable!(),
+        }),
+    };
+
+    series
+        .map(|series| Abstract(Rc::new(RefCell::new(series))))
+        .to_ocaml(cr)
 }
 
 #[ocaml_interop_export]
@@ -310,7 +536,7 @@ fn rust_series_date_range(
     series.to_ocaml(cr)
 }
 
-fn series_to_boxrooted_ocaml_list(
+pub fn series_to_boxrooted_ocaml_list(
     cr: &mut &mut OCamlRuntime,
     data_type: &GADTDataType,
     series: &Series,
@@ -436,6 +662,145 @@ fn series_to_boxrooted_ocaml_list(
                 buf
             })
         }
+        GADTDataType::Date => {
+            let ca = series.date().map_err(|err| err.to_string())?;
+
+            create_boxrooted_ocaml_list_handle_nulls!(
+                Abstract<NaiveDate>,
+                DynBox<NaiveDate>,
+                ca.as_date_iter().map(|date| date.map(Abstract)).collect(),
+                ca.as_date_iter().flatten().map(Abstract).collect()
+            )
+        }
+        GADTDataType::Datetime(_, _) => {
+            let ca = series.datetime().map_err(|err| err.to_string())?;
+
+            create_boxrooted_ocaml_list_handle_nulls!(
+                Abstract<NaiveDateTime>,
+                DynBox<NaiveDateTime>,
+                ca.as_datetime_iter()
+                    .map(|datetime| datetime.map(Abstract))
+                    .collect(),
+                ca.as_datetime_iter().flatten().map(Abstract).collect()
+            )
+        }
+        GADTDataType::Duration(_) => {
+            let ca = series.duration().map_err(|err| err.to_string())?;
+            let values = ca
+                .deref()
+                .into_iter()
+                .map(|duration| {
+                    duration
+                        .map(|duration| duration_to_nanoseconds(duration, ca.time_unit()))
+                        .transpose()
+                })
+                .collect::<Result<Vec<Option<i64>>, String>>()?;
+
+            create_boxrooted_ocaml_list_handle_nulls!(
+                i64,
+                OCamlInt,
+                values,
+                values.into_iter().flatten().collect()
+            )
+        }
+        GADTDataType::Time => {
+            let ca = series.time().map_err(|err| err.to_string())?;
+
+            create_boxrooted_ocaml_list_handle_nulls!(
+                i64,
+                OCamlInt,
+                ca.deref().into_iter().collect(),
+                ca.deref().into_no_null_iter().collect()
+            )
+        }
+        GADTDataType::Decimal(_, scale) => {
+            let ca = series.decimal().map_err(|err| err.to_string())?;
+            check_decimal_scale(ca.scale(), *scale)?;
+            let values = ca
+                .deref()
+                .into_iter()
+                .map(|value| value.map(decimal_to_scaled_int).transpose())
+                .collect::<Result<Vec<Option<i64>>, String>>()?;
+
+            create_boxrooted_ocaml_list_handle_nulls!(
+                i64,
+                OCamlInt,
+                values,
+                values.into_iter().flatten().collect()
+            )
+        }
+        GADTDataType::Struct(fields) => {
+            let ca = series.struct_().map_err(|err| err.to_string())?;
+            // See the comment in series_new: null values are rows where every
+            // field is null, which is exactly what is_null checks for.
+            let is_valid = !&series.is_null();
+
+            // Build the nested pairs from the innermost (unit) one outwards.
+            let mut rows: Vec<DummyBoxRoot> = (0..is_valid.sum().unwrap_or(0))
+                .map(|_| {
+                    let unit: BoxRoot<()> = ().to_ocaml(cr).root();
+                    unsafe { DummyBoxRoot::new(unit) }
+                })
+                .collect();
+            for (field_name, field_data_type) in fields.iter().rev() {
+                let field = ca
+                    .field_by_name(field_name)
+                    .and_then(|field| field.filter(&is_valid))
+                    .map_err(|err| err.to_string())?;
+                // See comment on similar recursive call in series_new on why allow_nulls=false
+                let values: Vec<DummyBoxRoot> =
+                    series_to_boxrooted_ocaml_list(cr, field_data_type, &field, false)?
+                        .interpret::<OCamlList<DummyBoxRoot>>(cr)
+                        .to_rust();
+                rows = values
+                    .into_iter()
+                    .zip(rows)
+                    .map(|(value, rest)| {
+                        let row: BoxRoot<(DummyBoxRoot, DummyBoxRoot)> =
+                            (value, rest).to_ocaml(cr).root();
+                        unsafe { DummyBoxRoot::new(row) }
+                    })
+                    .collect();
+            }
+
+            create_boxrooted_ocaml_list_handle_nulls!(
+                DummyBoxRoot,
+                DummyBoxRoot,
+                {
+                    let mut rows = rows.into_iter();
+                    is_valid
+                        .into_iter()
+                        .map(|is_valid| match is_valid {
+                            Some(true) => rows.next(),
+                            _ => None,
+                        })
+                        .collect()
+                },
+                rows
+            )
+        }
+        GADTDataType::Array(data_type, _) => {
+            let ca = series.array().map_err(|err| err.to_string())?;
+
+            // See comment on similar recursive call in series_new on why allow_nulls=false
+            create_boxrooted_ocaml_list_handle_nulls!(
+                DummyBoxRoot,
+                DummyBoxRoot,
+                ca.into_iter()
+                    .map(|serieso| {
+                        serieso
+                            .map(|series| {
+                                series_to_boxrooted_ocaml_list(cr, data_type, &series, false)
+                            })
+                            .transpose()
+                    })
+                    .collect::<Result<_, _>>()?,
+                ca.into_iter()
+                    .flatten()
+                    .map(|series| series_to_boxrooted_ocaml_list(cr, data_type, &series, false))
+                    .collect::<Result<_, _>>()?
+            )
+        }
         GADTDataType::List(data_type) => {
             let ca = series.list().map_err(|err| err.to_string())?;
 
@@ -507,6 +872,143 @@ fn rust_series_to_option_list(
     series_to_boxrooted_ocaml_list(cr, &data_type, &series, true)?.to_ocaml(cr)
 }
 
+// The constructors of OCaml's Bigarray.kind are represented as immediates, in
+// the order they are declared in bigarray.mli.
+fn bigarray_kind_data_type(kind: i64) -> Result<DataType, String> {
+    match kind {
+        0 => Ok(DataType::Float32),
+        1 => Ok(DataType::Float64),
+        2 => Ok(DataType::Int8),
+        3 => Ok(DataType::UInt8),
+        4 => Ok(DataType::Int16),
+        5 => Ok(DataType::UInt16),
+        6 => Ok(DataType::Int32),
+        7 => Ok(DataType::Int64),
+        _ => Err(format!(
+            "Unsupported Bigarray kind {}, expected one of float32, float64, int8_signed, \
+             int8_unsigned, int16_signed, int16_unsigned, int32 or int64",
+            kind
+        )),
+    }
+}
+
+// Whether `cast` (the result of casting `series`) kept all of its information,
+// i.e. casting it back gives the original values. Truncating floats to
+// integers or rounding large integers to the nearest float doesn't.
+fn is_lossless_cast(series: &Series, cast: &Series) -> PolarsResult<bool> {
+    let round_trip = cast.cast(series.dtype())?;
+    let mut same = round_trip.equal_missing(series)?;
+    if series.dtype().is_float() {
+        let both_nan = series.is_nan()?.fill_null_with_values(false)?
+            & round_trip.is_nan()?.fill_null_with_values(false)?;
+        same = same | both_nan;
+    }
+    Ok(same.all())
+}
+
+// Copies the values of a numeric series into a Bigarray in one go after
+// rechunking. Values at null positions are unspecified; a validity mask (1 for
+// valid, 0 for null) is returned alongside the values whenever the series
+// contains nulls. Casts which lose information fail unless `allow_lossy`.
+//
+// This always copies, even for a single chunk: sharing the Arrow buffer would
+// need a Bigarray which keeps a reference to it alive, and OCaml only lets
+// Bigarrays own memory which can be released with `free` (sub-arrays of
+// external Bigarrays don't keep their parent alive).
+fn series_to_bigarray(
+    cr: &mut &mut OCamlRuntime,
+    series: &Series,
+    kind: i64,
+    allow_lossy: bool,
+) -> Result<(DummyBoxRoot, Option<BoxRoot<Array1<u8>>>), String> {
+    let data_type = bigarray_kind_data_type(kind)?;
+    let cast = series
+        .strict_cast(&data_type)
+        .map_err(|err| err.to_string())?;
+    if !allow_lossy && !is_lossless_cast(series, &cast).map_err(|err| err.to_string())? {
+        return Err(format!(
+            "casting {} from {} to {} would lose information",
+            series.name(),
+            series.dtype(),
+            data_type
+        ));
+    }
+    let series = cast;
+
+    macro_rules! to_bigarray {
+        ($rust_type:ty, $ca:expr) => {{
+            let ca = $ca.map_err(|err| err.to_string())?.rechunk();
+            let array = ca.downcast_iter().next();
+            let values: &[$rust_type] = array.map_or(&[], |array| array.values().as_slice());
+            let validity: Option<Vec<u8>> = array
+                .and_then(|array| array.validity())
+                .filter(|validity| validity.unset_bits() > 0)
+                .map(|validity| validity.iter().map(u8::from).collect());
+
+            let values: BoxRoot<Array1<$rust_type>> = values.to_ocaml(cr).root();
+            let validity: Option<BoxRoot<Array1<u8>>> =
+                validity.map(|validity| validity.as_slice().to_ocaml(cr).root());
+
+            Ok((unsafe { DummyBoxRoot::new(values) }, validity))
+        }};
+    }
+
+    match data_type {
+        DataType::Float32 => to_bigarray!(f32, series.f32()),
+        DataType::Float64 => to_bigarray!(f64, series.f64()),
+        DataType::Int8 => to_bigarray!(i8, series.i8()),
+        DataType::UInt8 => to_bigarray!(u8, series.u8()),
+        DataType::Int16 => to_bigarray!(i16, series.i16()),
+        DataType::UInt16 => to_bigarray!(u16, series.u16()),
+        DataType::Int32 => to_bigarray!(i32, series.i32()),
+        DataType::Int64 => to_bigarray!(i64, series.i64()),
+        _ => unreachable!(),
+    }
+}
+
+#[ocaml_interop_export]
+fn rust_series_to_bigarray(
+    cr: &mut &mut OCamlRuntime,
+    series: OCamlRef<DynBox<PolarsSeries>>,
+    kind: OCamlRef<OCamlInt>,
+    allow_lossy: OCamlRef<bool>,
+) -> OCaml<Result<(DummyBoxRoot, Option<Array1<u8>>), String>> {
+    let Abstract(series) = series.to_rust(cr);
+    let kind: i64 = kind.to_rust(cr);
+    let allow_lossy: bool = allow_lossy.to_rust(cr);
+    let series = series.borrow();
+
+    series_to_bigarray(cr, &series, kind, allow_lossy).to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_series_export_to_c(
+    cr: &mut &mut OCamlRuntime,
+    series: OCamlRef<DynBox<PolarsSeries>>,
+    schema: OCamlRef<OCamlInt>,
+    array: OCamlRef<OCamlInt>,
+) -> OCaml<Result<(), String>> {
+    let Abstract(series) = series.to_rust(cr);
+    let schema: i64 = schema.to_rust(cr);
+    let array: i64 = array.to_rust(cr);
+
+    unsafe { arrow_ffi::export_series(&series.borrow(), schema, array) }.to_ocaml(cr)
+}
+
+#[ocaml_interop_export]
+fn rust_series_import_from_c(
+    cr: &mut &mut OCamlRuntime,
+    schema: OCamlRef<OCamlInt>,
+    array: OCamlRef<OCamlInt>,
+) -> OCaml<Result<DynBox<PolarsSeries>, String>> {
+    let schema: i64 = schema.to_rust(cr);
+    let array: i64 = array.to_rust(cr);
+
+    unsafe { arrow_ffi::import_series(schema, array) }
+        .map(|series| Abstract(Rc::new(RefCell::new(series))))
+        .to_ocaml(cr)
+}
+
 fn series_get(
     cr: &mut &mut OCamlRuntime,
     data_type: &GADTDataType,
@@ -574,6 +1076,74 @@ fn series_get(
             String,
             series.binary().map_err(|err| err.to_string())?.get(index)
         ),
+        GADTDataType::Date => extract_value!(
+            Abstract<NaiveDate>,
+            DynBox<NaiveDate>,
+            series
+                .date()
+                .map_err(|err| err.to_string())?
+                .get(index)
+                .map(|days| Abstract(date32_to_date(days)))
+        ),
+        GADTDataType::Datetime(_, _) => {
+            let ca = series.datetime().map_err(|err| err.to_string())?;
+            extract_value!(
+                Abstract<NaiveDateTime>,
+                DynBox<NaiveDateTime>,
+                ca.get(index).map(|timestamp| {
+                    Abstract(timestamp_to_naive_datetime(timestamp, ca.time_unit()))
+                })
+            )
+        }
+        GADTDataType::Duration(_) => {
+            let ca = series.duration().map_err(|err| err.to_string())?;
+            extract_value!(
+                i64,
+                OCamlInt,
+                ca.get(index)
+                    .map(|duration| duration_to_nanoseconds(duration, ca.time_unit()))
+                    .transpose()?
+            )
+        }
+        GADTDataType::Time => extract_value!(
+            i64,
+            OCamlInt,
+            series.time().map_err(|err| err.to_string())?.get(index)
+        ),
+        GADTDataType::Decimal(_, scale) => {
+            let ca = series.decimal().map_err(|err| err.to_string())?;
+            check_decimal_scale(ca.scale(), *scale)?;
+            extract_value!(
+                i64,
+                OCamlInt,
+                ca.get(index).map(decimal_to_scaled_int).transpose()?
+            )
+        }
+        GADTDataType::Struct(_) => extract_value!(
+            DummyBoxRoot,
+            DummyBoxRoot,
+            series_to_boxrooted_ocaml_list(cr, data_type, &series.slice(index as i64, 1), true)?
+                .interpret::<OCamlList<Option<DummyBoxRoot>>>(cr)
+                .to_rust::<Vec<Option<DummyBoxRoot>>>()
+                .pop()
+                .flatten()
+        ),
+        GADTDataType::Array(data_type, _) => {
+            let element = series.slice(index as i64, 1);
+            let element = element
+                .array()
+                .map_err(|err| err.to_string())?
+                .into_iter()
+                .next()
+                .flatten();
+            extract_value!(
+                DummyBoxRoot,
+                DummyBoxRoot,
+                element
+                    .map(|series| series_to_boxrooted_ocaml_list(cr, data_type, &series, false))
+                    .transpose()?
+            )
+        }
         GADTDataType::List(data_type) => extract_value!(
             DummyBoxRoot,
             DummyBoxRoot,
@@ -602,6 +1172,19 @@ fn rust_series_get(
     series_get(cr, &data_type, &series, index)?.to_ocaml(cr)
 }
 
+#[ocaml_interop_export]
+fn rust_series_get_any_value(
+    cr: &mut &mut OCamlRuntime,
+    series: OCamlRef<DynBox<PolarsSeries>>,
+    index: OCamlRef<OCamlInt>,
+) -> OCaml<Result<AnyValue<'static>, String>> {
+    let Abstract(series) = series.to_rust(cr);
+    let series = series.borrow();
+    let index: i64 = index.to_rust(cr);
+
+    any_value_at(&series, index).to_ocaml(cr)
+}
+
 #[ocaml_interop_export]
 fn rust_series_name(
     cr: &mut &mut OCamlRuntime,
@@ -1,16 +1,26 @@
+use chrono::naive::{NaiveDate, NaiveDateTime};
 use ocaml_interop::{
-    impl_from_ocaml_variant, ocaml_alloc_polymorphic_variant, ocaml_alloc_tagged_block,
-    ocaml_alloc_variant, ocaml_unpack_polymorphic_variant, ocaml_unpack_variant,
-    polymorphic_variant_tag_hash, BoxRoot, DynBox, FromOCaml, OCaml, OCamlInt, OCamlList,
-    OCamlRuntime, ToOCaml,
+    bigarray::Array1, impl_from_ocaml_variant, ocaml_alloc_polymorphic_variant,
+    ocaml_alloc_tagged_block, ocaml_alloc_variant, ocaml_unpack_polymorphic_variant,
+    ocaml_unpack_variant, polymorphic_variant_tag_hash, BoxRoot, DynBox, FromOCaml, OCaml,
+    OCamlBytes, OCamlFloat, OCamlInt, OCamlList, OCamlRuntime, ToOCaml,
 };
+use polars::export::arrow::temporal_conversions::{
+    date32_to_date, timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use polars::io::mmap::MmapBytesReader;
+use polars::io::RowCount;
 use polars::series::IsSorted;
 use polars::{lazy::dsl::WindowMapping, prelude::*};
 use smartstring::{LazyCompact, SmartString};
 use std::any::type_name;
 use std::borrow::Borrow;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+use std::fs::File;
+use std::io::{Cursor, Write};
 use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::Mutex;
 
 macro_rules! dyn_box {
     ($cr:ident, |$($var:ident),+| $body:expr) => {
@@ -100,7 +110,8 @@ where
         let size = unsafe { ocaml_sys::wosize_val(v.raw()) };
 
         // tuple/record/array tag, note that we do not expect a double array
-        // tag, since uniform array guarantee boxing.
+        // tag, since uniform array guarantee boxing. See UniformArrayValues
+        // for a conversion which also accepts regular float arrays.
         assert_eq!(v.tag_value(), 0);
 
         let mut vec = Vec::with_capacity(size);
@@ -111,6 +122,55 @@ where
     }
 }
 
+// Reads the contents of a block with Double_array_tag. Each double takes up
+// exactly one word on 64-bit platforms, which are the only ones OCaml 5 (and
+// this library) supports.
+unsafe fn flat_float_array_values<A>(v: &OCaml<A>) -> Vec<f64> {
+    let size = ocaml_sys::wosize_val(v.raw());
+    std::slice::from_raw_parts(v.raw() as *const f64, size).to_vec()
+}
+
+// The contents of a regular OCaml array passed where an OCamlUniformArray is
+// expected. Arrays of floats are stored flat (with Double_array_tag) unless
+// they come from Uniform_array, so rather than boxing every element we read the
+// doubles directly in that case.
+pub enum UniformArrayValues<A> {
+    Boxed(Vec<A>),
+    Floats(Vec<f64>),
+}
+
+unsafe impl<A, OCamlA> FromOCaml<OCamlUniformArray<OCamlA>> for UniformArrayValues<A>
+where
+    A: FromOCaml<OCamlA>,
+{
+    fn from_ocaml(v: OCaml<OCamlUniformArray<OCamlA>>) -> Self {
+        if v.tag_value() == ocaml_sys::DOUBLE_ARRAY {
+            UniformArrayValues::Floats(unsafe { flat_float_array_values(&v) })
+        } else {
+            UniformArrayValues::Boxed(v.to_rust())
+        }
+    }
+}
+
+// An OCaml `float array`, which (unless the compiler is configured with
+// -no-flat-float-array) is stored unboxed with Double_array_tag, except for the
+// empty array which is the atom with tag 0.
+pub struct OCamlFloatArray {
+    _marker: PhantomData<f64>,
+}
+
+unsafe impl FromOCaml<OCamlFloatArray> for Vec<f64> {
+    fn from_ocaml(v: OCaml<OCamlFloatArray>) -> Self {
+        if unsafe { ocaml_sys::wosize_val(v.raw()) } == 0 {
+            return Vec::new();
+        }
+
+        assert_eq!(v.tag_value(), ocaml_sys::DOUBLE_ARRAY);
+
+        unsafe { flat_float_array_values(&v) }
+    }
+}
+
 pub struct OCamlInt63(pub i64);
 
 unsafe impl FromOCaml<OCamlInt63> for OCamlInt63 {
@@ -201,6 +261,17 @@ unsafe impl FromOCaml<DataType> for PolarsDataType {
                         .collect();
                     DataType::Struct(fields)
                 },
+                DataType::Categorical => DataType::Categorical(None),
+                DataType::Decimal(precision: Option<OCamlInt>, scale: Option<OCamlInt>) => {
+                    let precision: Option<i64> = precision;
+                    let scale: Option<i64> = scale;
+                    DataType::Decimal(precision.map(ocaml_int_to_usize), scale.map(ocaml_int_to_usize))
+                },
+                DataType::Array(datatype: DataType, width: OCamlInt) => {
+                    let PolarsDataType(datatype) = datatype;
+                    let width: i64 = width;
+                    DataType::Array(Box::new(datatype), ocaml_int_to_usize(width))
+                },
                 DataType::Unknown,
             }
         };
@@ -208,6 +279,13 @@ unsafe impl FromOCaml<DataType> for PolarsDataType {
     }
 }
 
+fn ocaml_int_to_usize(n: i64) -> usize {
+    match usize::try_from(n) {
+        Ok(n) => n,
+        Err(_) => unsafe { ocaml_failwith(&format!("Failed conversion to usize {:?}", n)) },
+    }
+}
+
 unsafe fn ocaml_value<T>(cr: &OCamlRuntime, n: i32) -> OCaml<T> {
     unsafe { OCaml::new(cr, OCaml::of_i32(n).raw()) }
 }
@@ -255,13 +333,24 @@ unsafe impl ToOCaml<DataType> for PolarsDataType {
                         .collect();
                     ocaml_alloc_tagged_block!(cr, 3, fields: OCamlList<(String, DataType)>)
                 }
-                DataType::Unknown => ocaml_value(cr, 16),
+                DataType::Categorical(_) => ocaml_value(cr, 16),
+                DataType::Decimal(precision, scale) => {
+                    let precision = precision.map(|precision| precision as i64);
+                    let scale = scale.map(|scale| scale as i64);
+                    ocaml_alloc_tagged_block!(cr, 4, precision: Option<OCamlInt>, scale: Option<OCamlInt>)
+                }
+                DataType::Array(datatype, width) => {
+                    let datatype = PolarsDataType(*datatype.clone());
+                    let width = *width as i64;
+                    ocaml_alloc_tagged_block!(cr, 5, datatype: DataType, width: OCamlInt)
+                }
+                DataType::Unknown => ocaml_value(cr, 17),
             }
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum GADTDataType {
     Boolean,
     UInt8,
@@ -276,7 +365,14 @@ pub enum GADTDataType {
     Float64,
     Utf8,
     Binary,
+    Date,
+    Datetime(TimeUnit, Option<String>),
+    Duration(TimeUnit),
+    Time,
     List(Box<GADTDataType>),
+    Struct(Vec<(String, GADTDataType)>),
+    Decimal(Option<usize>, usize),
+    Array(Box<GADTDataType>, usize),
 }
 
 impl_from_ocaml_variant! {
@@ -294,7 +390,70 @@ impl_from_ocaml_variant! {
         GADTDataType::Float64,
         GADTDataType::Utf8,
         GADTDataType::Binary,
+        GADTDataType::Date,
+        GADTDataType::Datetime(timeunit: TimeUnit, timezone: Option<String>) => {
+            let PolarsTimeUnit(timeunit) = timeunit;
+            GADTDataType::Datetime(timeunit, timezone)
+        },
+        GADTDataType::Duration(timeunit: TimeUnit) => {
+            let PolarsTimeUnit(timeunit) = timeunit;
+            GADTDataType::Duration(timeunit)
+        },
+        GADTDataType::Time,
         GADTDataType::List(data_type: GADTDataType),
+        GADTDataType::Struct(fields: GADTStructFields) => {
+            let fields: Vec<(String, GADTDataType)> = fields;
+            GADTDataType::Struct(fields)
+        },
+        GADTDataType::Decimal(precision: Option<OCamlInt>, scale: OCamlInt) => {
+            let precision: Option<i64> = precision;
+            let scale: i64 = scale;
+            GADTDataType::Decimal(precision.map(ocaml_int_to_usize), ocaml_int_to_usize(scale))
+        },
+        GADTDataType::Array(data_type: GADTDataType, width: OCamlInt) => {
+            let width: i64 = width;
+            GADTDataType::Array(Box::new(data_type), ocaml_int_to_usize(width))
+        },
+    }
+}
+
+// The fields of a struct in the typed API, which on the OCaml side are a
+// heterogeneous list: `Field (name, data_type, rest)` is a block and `Empty` is
+// an immediate.
+pub struct GADTStructFields {}
+
+unsafe impl FromOCaml<GADTStructFields> for Vec<(String, GADTDataType)> {
+    fn from_ocaml(v: OCaml<GADTStructFields>) -> Self {
+        let mut fields = Vec::new();
+        let mut v = v;
+        while v.is_block() {
+            unsafe {
+                let name: String = v.field::<String>(0).to_rust();
+                let data_type: GADTDataType = v.field::<GADTDataType>(1).to_rust();
+                fields.push((name, data_type));
+                v = v.field::<GADTStructFields>(2);
+            }
+        }
+        fields
+    }
+}
+
+// The data types of the columns of a row in the typed API (Row_type.t), which
+// on the OCaml side is a heterogeneous list like GADTStructFields but without
+// names.
+pub struct GADTRowType {}
+
+unsafe impl FromOCaml<GADTRowType> for Vec<GADTDataType> {
+    fn from_ocaml(v: OCaml<GADTRowType>) -> Self {
+        let mut data_types = Vec::new();
+        let mut v = v;
+        while v.is_block() {
+            unsafe {
+                data_types.push(v.field::<GADTDataType>(0).to_rust());
+                v = v.field::<GADTRowType>(1);
+            }
+        }
+        data_types
     }
 }
 
@@ -314,11 +473,609 @@ impl GADTDataType {
             GADTDataType::Float64 => DataType::Float64,
             GADTDataType::Utf8 => DataType::Utf8,
             GADTDataType::Binary => DataType::Binary,
+            GADTDataType::Date => DataType::Date,
+            GADTDataType::Datetime(timeunit, timezone) => {
+                DataType::Datetime(*timeunit, timezone.clone())
+            }
+            GADTDataType::Duration(timeunit) => DataType::Duration(*timeunit),
+            GADTDataType::Time => DataType::Time,
             GADTDataType::List(data_type) => DataType::List(Box::new(data_type.to_data_type())),
+            GADTDataType::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, data_type)| Field::new(name, data_type.to_data_type()))
+                    .collect(),
+            ),
+            GADTDataType::Decimal(precision, scale) => DataType::Decimal(*precision, Some(*scale)),
+            GADTDataType::Array(data_type, width) => {
+                DataType::Array(Box::new(data_type.to_data_type()), *width)
+            }
         }
     }
 }
 
+// Conversions between the OCaml representations of temporal values used by
+// the typed API and the physical values of the corresponding Polars data
+// types. Durations and times of day are represented in OCaml by
+// Time_ns.Span.t and Time_ns.Ofday.t, i.e. as integer nanoseconds.
+pub fn naive_date_to_days(date: NaiveDate) -> i32 {
+    (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
+}
+
+pub fn naive_datetime_to_timestamp(
+    datetime: NaiveDateTime,
+    timeunit: TimeUnit,
+) -> Result<i64, String> {
+    match timeunit {
+        TimeUnit::Nanoseconds => datetime
+            .timestamp_nanos_opt()
+            .ok_or_else(|| format!("out of range datetime: {:?}", datetime)),
+        TimeUnit::Microseconds => Ok(datetime.timestamp_micros()),
+        TimeUnit::Milliseconds => Ok(datetime.timestamp_millis()),
+    }
+}
+
+pub fn timestamp_to_naive_datetime(timestamp: i64, timeunit: TimeUnit) -> NaiveDateTime {
+    match timeunit {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime(timestamp),
+        TimeUnit::Microseconds => timestamp_us_to_datetime(timestamp),
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime(timestamp),
+    }
+}
+
+pub fn nanoseconds_per_unit(timeunit: TimeUnit) -> i64 {
+    match timeunit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+const OCAML_INT_MAX: i128 = (1 << 62) - 1;
+const OCAML_INT_MIN: i128 = -(1 << 62);
+
+fn fits_in_ocaml_int(value: i128) -> bool {
+    (OCAML_INT_MIN..=OCAML_INT_MAX).contains(&value)
+}
+
+// Durations in a coarser time unit than nanoseconds may not fit in a
+// Time_ns.Span.t once converted.
+pub fn duration_to_nanoseconds(duration: i64, timeunit: TimeUnit) -> Result<i64, String> {
+    duration
+        .checked_mul(nanoseconds_per_unit(timeunit))
+        .filter(|nanoseconds| fits_in_ocaml_int(*nanoseconds as i128))
+        .ok_or_else(|| {
+            format!(
+                "duration {} {} does not fit in a Time_ns.Span.t",
+                duration, timeunit
+            )
+        })
+}
+
+// Polars' Time values are nanoseconds since midnight, so unlike a
+// Time_ns.Ofday.t they can't be the start of the next day.
+const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+
+pub fn time_of_day_to_nanoseconds(nanoseconds: i64) -> Result<i64, String> {
+    if (0..NANOSECONDS_PER_DAY).contains(&nanoseconds) {
+        Ok(nanoseconds)
+    } else {
+        Err(format!(
+            "time of day {}ns is outside of [0ns, {}ns)",
+            nanoseconds, NANOSECONDS_PER_DAY
+        ))
+    }
+}
+
+// Decimals are represented in the typed API by their value scaled by
+// 10^scale, e.g. 12.34 with scale 2 is 1234. Polars stores them as i128, so
+// values outside of the range of OCaml ints can't be converted.
+pub fn decimal_to_scaled_int(value: i128) -> Result<i64, String> {
+    if fits_in_ocaml_int(value) {
+        Ok(value as i64)
+    } else {
+        Err(format!(
+            "decimal value {} does not fit in an OCaml int",
+            value
+        ))
+    }
+}
+
+pub fn check_decimal_scale(actual: usize, expected: usize) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "Decimal series has scale {}, expected {}",
+            actual, expected
+        ))
+    }
+}
+
+pub fn format_decimal(value: i128, scale: usize) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let value = value.unsigned_abs();
+    if scale == 0 {
+        return format!("{}{}", sign, value);
+    }
+    let factor = 10_u128.pow(scale as u32);
+    format!(
+        "{}{}.{:0scale$}",
+        sign,
+        value / factor,
+        value % factor,
+        scale = scale
+    )
+}
+
+// Polars 0.32.1's CSV writer can't serialize decimals, so we write them out
+// as strings. Its CSV reader can't be trusted with them either, as it loses the
+// sign of values between -1 and 0 ("-0.5" is parsed as 0.5), so our readers
+// read decimal columns as strings too (see `decimals_as_utf8`) and parse them
+// with `parse_decimal_columns`.
+pub fn decimals_to_strings(data_frame: &DataFrame) -> PolarsResult<DataFrame> {
+    data_frame
+        .get_columns()
+        .iter()
+        .map(|series| match series.dtype() {
+            DataType::Decimal(_, _) => {
+                let ca = series.decimal()?;
+                let scale = ca.scale();
+                Ok(Utf8Chunked::from_iter_options(
+                    series.name(),
+                    ca.deref()
+                        .into_iter()
+                        .map(|value| value.map(|value| format_decimal(value, scale))),
+                )
+                .into_series())
+            }
+            _ => Ok(series.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .map(DataFrame::new_no_checks)
+}
+
+pub fn decimals_as_utf8(schema: &Schema) -> Schema {
+    schema
+        .iter()
+        .map(|(name, data_type)| match data_type {
+            DataType::Decimal(_, _) => Field::new(name, DataType::Utf8),
+            _ => Field::new(name, data_type.clone()),
+        })
+        .collect()
+}
+
+// Parses e.g. "-0.50" with scale 3 into -500, or returns None if the string
+// isn't a decimal number with at most `scale` digits after the point.
+fn parse_decimal(value: &str, scale: usize) -> Option<i128> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || fraction.len() > scale
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let magnitude = integer
+        .chars()
+        .chain(fraction.chars())
+        .chain(std::iter::repeat('0').take(scale - fraction.len()))
+        .try_fold(0_i128, |acc, digit| {
+            acc.checked_mul(10)?
+                .checked_add(digit.to_digit(10)? as i128)
+        })?;
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+fn fraction_digits(value: &str) -> usize {
+    value
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+// Turns a column of decimals read as strings into a Decimal column. A missing
+// scale is inferred from the values.
+fn parse_decimal_series(
+    series: &Series,
+    precision: Option<usize>,
+    scale: Option<usize>,
+) -> PolarsResult<Series> {
+    let ca = series.utf8()?;
+    let scale = scale.unwrap_or_else(|| {
+        ca.into_iter()
+            .flatten()
+            .map(fraction_digits)
+            .max()
+            .unwrap_or(0)
+    });
+    let values = ca
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    parse_decimal(value, scale).ok_or_else(|| {
+                        polars_err!(
+                            ComputeError:
+                            "could not parse {:?} in column {} as a decimal with scale {}",
+                            value,
+                            series.name(),
+                            scale
+                        )
+                    })
+                })
+                .transpose()
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Int128Chunked::from_iter_options(series.name(), values.into_iter())
+        .into_decimal(precision, scale)
+        .map(|ca| ca.into_series())
+}
+
+// Parses the columns which `schema` says are decimals, and which were read as
+// strings.
+pub fn parse_decimal_columns(data_frame: DataFrame, schema: &Schema) -> PolarsResult<DataFrame> {
+    data_frame
+        .get_columns()
+        .iter()
+        .map(|series| match (schema.get(series.name()), series.dtype()) {
+            (Some(DataType::Decimal(precision, scale)), DataType::Utf8) => {
+                parse_decimal_series(series, *precision, *scale)
+            }
+            _ => Ok(series.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .map(DataFrame::new_no_checks)
+}
+
+// The lazy counterpart of `parse_decimal_columns`.
+pub fn parse_decimal_columns_lazy(
+    lazy_frame: LazyFrame,
+    schema: &Schema,
+) -> PolarsResult<LazyFrame> {
+    let read_schema = lazy_frame.schema()?;
+    let exprs: Vec<Expr> = schema
+        .iter()
+        .filter_map(|(name, data_type)| match data_type {
+            DataType::Decimal(precision, scale)
+                if read_schema.get(name) == Some(&DataType::Utf8) =>
+            {
+                let (precision, scale) = (*precision, *scale);
+                Some(col(name).map(
+                    move |series| parse_decimal_series(&series, precision, scale).map(Some),
+                    GetOutput::from_type(data_type.clone()),
+                ))
+            }
+            _ => None,
+        })
+        .collect();
+    Ok(if exprs.is_empty() {
+        lazy_frame
+    } else {
+        lazy_frame.with_columns(exprs)
+    })
+}
+
+// Values of any data type, represented in OCaml by Any_value.t. Temporal
+// values and decimals use the same representations as in the typed API,
+// categoricals become strings and fixed-size arrays become lists.
+//
+// Not every value has such a representation (OCaml ints have only 63 bits), so
+// values are checked by `PolarsAnyValue::new` and converting them to OCaml
+// can't fail.
+pub struct PolarsAnyValue<'a>(AnyValue<'a>);
+
+impl<'a> PolarsAnyValue<'a> {
+    pub fn new(value: AnyValue<'a>) -> Result<Self, String> {
+        check_ocaml_representable(&value).map(|()| PolarsAnyValue(value))
+    }
+}
+
+fn check_ocaml_int<T: TryInto<i128> + Copy + Display>(value: T) -> Result<(), String> {
+    match value.try_into() {
+        Ok(value) if fits_in_ocaml_int(value) => Ok(()),
+        _ => Err(format!("{} does not fit in an OCaml int", value)),
+    }
+}
+
+fn check_ocaml_representable(value: &AnyValue) -> Result<(), String> {
+    match value {
+        AnyValue::UInt64(value) => check_ocaml_int(*value),
+        AnyValue::Int64(value) => check_ocaml_int(*value),
+        AnyValue::Datetime(timestamp, timeunit, _) => {
+            let in_range = match timeunit {
+                TimeUnit::Nanoseconds => true,
+                TimeUnit::Microseconds => {
+                    NaiveDateTime::from_timestamp_micros(*timestamp).is_some()
+                }
+                TimeUnit::Milliseconds => {
+                    NaiveDateTime::from_timestamp_millis(*timestamp).is_some()
+                }
+            };
+            if in_range {
+                Ok(())
+            } else {
+                Err(format!(
+                    "datetime {} {} is out of range",
+                    timestamp, timeunit
+                ))
+            }
+        }
+        AnyValue::Duration(duration, timeunit) => {
+            duration_to_nanoseconds(*duration, *timeunit).map(|_| ())
+        }
+        AnyValue::Decimal(value, _) => decimal_to_scaled_int(*value).map(|_| ()),
+        AnyValue::List(series) | AnyValue::Array(series, _) => {
+            (0..series.len()).try_for_each(|i| {
+                series
+                    .get(i)
+                    .map_err(|err| err.to_string())
+                    .and_then(|value| check_ocaml_representable(&value))
+            })
+        }
+        AnyValue::Struct(_, _, _) => value
+            ._iter_struct_av()
+            .try_for_each(|value| check_ocaml_representable(&value)),
+        AnyValue::StructOwned(payload) => payload.0.iter().try_for_each(check_ocaml_representable),
+        _ => Ok(()),
+    }
+}
+
+fn series_any_values(series: &Series) -> Vec<PolarsAnyValue> {
+    (0..series.len())
+        .map(|i| match series.get(i) {
+            Ok(value) => PolarsAnyValue(value),
+            Err(err) => unsafe { ocaml_failwith(&err.to_string()) },
+        })
+        .collect()
+}
+
+pub fn any_value_at(series: &Series, index: i64) -> Result<PolarsAnyValue, String> {
+    usize::try_from(index)
+        .map_err(|_| format!("index {} is out of bounds", index))
+        .and_then(|index| series.get(index).map_err(|err| err.to_string()))
+        .and_then(PolarsAnyValue::new)
+}
+
+unsafe impl ToOCaml<AnyValue<'static>> for PolarsAnyValue<'_> {
+    fn to_ocaml<'a>(&self, cr: &'a mut OCamlRuntime) -> OCaml<'a, AnyValue<'static>> {
+        let PolarsAnyValue(value) = self;
+        unsafe {
+            match value {
+                AnyValue::Null => ocaml_value(cr, 0),
+                AnyValue::Boolean(value) => {
+                    let value = *value;
+                    ocaml_alloc_tagged_block!(cr, 0, value: bool)
+                }
+                AnyValue::UInt8(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 1, value: OCamlInt)
+                }
+                AnyValue::UInt16(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 2, value: OCamlInt)
+                }
+                AnyValue::UInt32(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 3, value: OCamlInt)
+                }
+                AnyValue::UInt64(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 4, value: OCamlInt)
+                }
+                AnyValue::Int8(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 5, value: OCamlInt)
+                }
+                AnyValue::Int16(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 6, value: OCamlInt)
+                }
+                AnyValue::Int32(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 7, value: OCamlInt)
+                }
+                AnyValue::Int64(value) => {
+                    let value = OCamlIntable(*value);
+                    ocaml_alloc_tagged_block!(cr, 8, value: OCamlInt)
+                }
+                AnyValue::Float32(value) => {
+                    let value = *value as f64;
+                    ocaml_alloc_tagged_block!(cr, 9, value: OCamlFloat)
+                }
+                AnyValue::Float64(value) => {
+                    let value = *value;
+                    ocaml_alloc_tagged_block!(cr, 10, value: OCamlFloat)
+                }
+                AnyValue::Utf8(_) | AnyValue::Utf8Owned(_) | AnyValue::Categorical(_, _, _) => {
+                    let value: &str = value.get_str().unwrap();
+                    ocaml_alloc_tagged_block!(cr, 11, value: String)
+                }
+                AnyValue::Binary(value) => {
+                    let value: &[u8] = value;
+                    ocaml_alloc_tagged_block!(cr, 12, value: String)
+                }
+                AnyValue::BinaryOwned(value) => {
+                    let value: &[u8] = value;
+                    ocaml_alloc_tagged_block!(cr, 12, value: String)
+                }
+                AnyValue::Date(days) => {
+                    let value = Abstract(date32_to_date(*days));
+                    ocaml_alloc_tagged_block!(cr, 13, value: DynBox<NaiveDate>)
+                }
+                AnyValue::Datetime(timestamp, timeunit, timezone) => {
+                    let value = Abstract(timestamp_to_naive_datetime(*timestamp, *timeunit));
+                    let timeunit = PolarsTimeUnit(*timeunit);
+                    let timezone: Option<String> = (*timezone).clone();
+                    ocaml_alloc_tagged_block!(cr, 14, value: DynBox<NaiveDateTime>, timeunit: TimeUnit, timezone: Option<String>)
+                }
+                AnyValue::Duration(duration, timeunit) => {
+                    let value = duration_to_nanoseconds(*duration, *timeunit)
+                        .expect("checked by PolarsAnyValue::new");
+                    let value = OCamlIntable(value);
+                    let timeunit = PolarsTimeUnit(*timeunit);
+                    ocaml_alloc_tagged_block!(cr, 15, value: OCamlInt, timeunit: TimeUnit)
+                }
+                AnyValue::Time(nanoseconds) => {
+                    let value = OCamlIntable(*nanoseconds);
+                    ocaml_alloc_tagged_block!(cr, 16, value: OCamlInt)
+                }
+                AnyValue::List(series) | AnyValue::Array(series, _) => {
+                    let values = series_any_values(series);
+                    ocaml_alloc_tagged_block!(cr, 17, values: OCamlList<AnyValue<'static>>)
+                }
+                AnyValue::Struct(_, _, fields) => {
+                    let fields: Vec<(String, PolarsAnyValue)> = fields
+                        .iter()
+                        .zip(value._iter_struct_av())
+                        .map(|(field, value)| (field.name.to_string(), PolarsAnyValue(value)))
+                        .collect();
+                    ocaml_alloc_tagged_block!(cr, 18, fields: OCamlList<(String, AnyValue<'static>)>)
+                }
+                AnyValue::StructOwned(payload) => {
+                    let (values, fields) = &**payload;
+                    let fields: Vec<(String, PolarsAnyValue)> = fields
+                        .iter()
+                        .zip(values)
+                        .map(|(field, value)| {
+                            (field.name.to_string(), PolarsAnyValue(value.clone()))
+                        })
+                        .collect();
+                    ocaml_alloc_tagged_block!(cr, 18, fields: OCamlList<(String, AnyValue<'static>)>)
+                }
+                AnyValue::Decimal(value, scale) => {
+                    let value =
+                        decimal_to_scaled_int(*value).expect("checked by PolarsAnyValue::new");
+                    let scale = *scale as i64;
+                    ocaml_alloc_tagged_block!(cr, 19, value: OCamlInt, scale: OCamlInt)
+                }
+            }
+        }
+    }
+}
+
+fn ocaml_int_to<T: TryFrom<i64>>(n: i64) -> Result<T, String> {
+    T::try_from(n).map_err(|_| format!("{} is out of range for {}", n, type_name::<T>()))
+}
+
+// AnyValue::Datetime only borrows its time zone, so time zones coming from
+// OCaml are interned (and never freed) to give them a 'static lifetime. Only
+// names from the time zone database are accepted, so at most a few hundred
+// strings are ever leaked.
+fn intern_time_zone(timezone: Option<String>) -> Result<&'static Option<TimeZone>, String> {
+    static NO_TIME_ZONE: Option<TimeZone> = None;
+    static TIME_ZONES: Mutex<Vec<&'static Option<TimeZone>>> = Mutex::new(Vec::new());
+
+    let Some(name) = &timezone else {
+        return Ok(&NO_TIME_ZONE);
+    };
+    let mut time_zones = TIME_ZONES.lock().unwrap();
+    if let Some(time_zone) = time_zones.iter().find(|time_zone| ***time_zone == timezone) {
+        return Ok(time_zone);
+    }
+    if name.parse::<chrono_tz::Tz>().is_err() {
+        return Err(format!("unable to parse time zone: '{}'", name));
+    }
+    let time_zone: &'static Option<TimeZone> = Box::leak(Box::new(timezone));
+    time_zones.push(time_zone);
+    Ok(time_zone)
+}
+
+// An AnyValue coming from OCaml, or the reason it can't be represented in
+// Polars (e.g. an integer out of range of its data type or an unknown time
+// zone). Errors are returned rather than raised so that callers can report
+// which value caused them.
+pub struct PolarsAnyValueResult(pub Result<AnyValue<'static>, String>);
+
+fn any_value_results(values: Vec<PolarsAnyValueResult>) -> Result<Vec<AnyValue<'static>>, String> {
+    values
+        .into_iter()
+        .map(|PolarsAnyValueResult(value)| value)
+        .collect()
+}
+
+unsafe impl FromOCaml<AnyValue<'static>> for PolarsAnyValueResult {
+    fn from_ocaml(v: OCaml<AnyValue<'static>>) -> Self {
+        let result = ocaml_unpack_variant! {
+            v => {
+                AnyValue::Null => Ok(AnyValue::Null),
+                AnyValue::Boolean(value: bool) => Ok(AnyValue::Boolean(value)),
+                AnyValue::UInt8(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::UInt8),
+                AnyValue::UInt16(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::UInt16),
+                AnyValue::UInt32(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::UInt32),
+                AnyValue::UInt64(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::UInt64),
+                AnyValue::Int8(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::Int8),
+                AnyValue::Int16(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::Int16),
+                AnyValue::Int32(value: OCamlInt) => ocaml_int_to(value).map(AnyValue::Int32),
+                AnyValue::Int64(value: OCamlInt) => Ok(AnyValue::Int64(value)),
+                AnyValue::Float32(value: OCamlFloat) => {
+                    let value: f64 = value;
+                    Ok(AnyValue::Float32(value as f32))
+                },
+                AnyValue::Float64(value: OCamlFloat) => Ok(AnyValue::Float64(value)),
+                AnyValue::Utf8(value: String) => {
+                    let value: String = value;
+                    Ok(AnyValue::Utf8Owned(value.into()))
+                },
+                AnyValue::Binary(value: OCamlBytes) => Ok(AnyValue::BinaryOwned(value)),
+                AnyValue::Date(date: DynBox<NaiveDate>) => {
+                    let Abstract(date): Abstract<NaiveDate> = date;
+                    Ok(AnyValue::Date(naive_date_to_days(date)))
+                },
+                AnyValue::Datetime(datetime: DynBox<NaiveDateTime>, timeunit: TimeUnit, timezone: Option<String>) => {
+                    let Abstract(datetime): Abstract<NaiveDateTime> = datetime;
+                    let PolarsTimeUnit(timeunit) = timeunit;
+                    naive_datetime_to_timestamp(datetime, timeunit).and_then(|timestamp| {
+                        intern_time_zone(timezone)
+                            .map(|timezone| AnyValue::Datetime(timestamp, timeunit, timezone))
+                    })
+                },
+                AnyValue::Duration(duration: OCamlInt63, timeunit: TimeUnit) => {
+                    let OCamlInt63(nanoseconds) = duration;
+                    let PolarsTimeUnit(timeunit) = timeunit;
+                    Ok(AnyValue::Duration(nanoseconds / nanoseconds_per_unit(timeunit), timeunit))
+                },
+                AnyValue::Time(time: OCamlInt63) => {
+                    let OCamlInt63(nanoseconds) = time;
+                    Ok(AnyValue::Time(nanoseconds))
+                },
+                AnyValue::List(values: OCamlList<AnyValue<'static>>) => {
+                    let values: Vec<PolarsAnyValueResult> = values;
+                    any_value_results(values).and_then(|values| {
+                        Series::from_any_values("", &values, true)
+                            .map(AnyValue::List)
+                            .map_err(|err| err.to_string())
+                    })
+                },
+                AnyValue::StructOwned(fields: OCamlList<(String, AnyValue<'static>)>) => {
+                    let fields: Vec<(String, PolarsAnyValueResult)> = fields;
+                    fields
+                        .into_iter()
+                        .map(|(name, PolarsAnyValueResult(value))| {
+                            value.map(|value| (Field::new(&name, value.dtype()), value))
+                        })
+                        .collect::<Result<Vec<(Field, AnyValue)>, String>>()
+                        .map(|fields| {
+                            let (fields, values): (Vec<Field>, Vec<AnyValue>) =
+                                fields.into_iter().unzip();
+                            AnyValue::StructOwned(Box::new((values, fields)))
+                        })
+                },
+                AnyValue::Decimal(value: OCamlInt, scale: OCamlInt) => {
+                    let value: i64 = value;
+                    ocaml_int_to(scale).map(|scale| AnyValue::Decimal(value as i128, scale))
+                },
+            }
+        };
+        PolarsAnyValueResult(result.expect("Failure when unpacking an OCaml<AnyValue> variant into PolarsAnyValueResult (unexpected tag value"))
+    }
+}
+
 pub struct PolarsFillNullStrategy(pub FillNullStrategy);
 
 unsafe impl FromOCaml<FillNullStrategy> for PolarsFillNullStrategy {
@@ -570,6 +1327,314 @@ unsafe impl FromOCaml<StartBy> for PolarsStartBy {
     }
 }
 
+pub struct PolarsUniqueKeepStrategy(pub UniqueKeepStrategy);
+
+unsafe impl FromOCaml<UniqueKeepStrategy> for PolarsUniqueKeepStrategy {
+    fn from_ocaml(v: OCaml<UniqueKeepStrategy>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                First => UniqueKeepStrategy::First,
+                Last => UniqueKeepStrategy::Last,
+                None => UniqueKeepStrategy::None,
+                Any => UniqueKeepStrategy::Any,
+            }
+        };
+        PolarsUniqueKeepStrategy(result.expect("Failure when unpacking an OCaml<UniqueKeepStrategy> variant into PolarsUniqueKeepStrategy (unexpected tag value"))
+    }
+}
+
+pub struct PolarsNullValues(pub NullValues);
+
+unsafe impl FromOCaml<NullValues> for PolarsNullValues {
+    fn from_ocaml(v: OCaml<NullValues>) -> Self {
+        let result = ocaml_unpack_variant! {
+            v => {
+                NullValues::AllColumnsSingle(value: String),
+                NullValues::AllColumns(values: OCamlList<String>),
+                NullValues::Named(values: OCamlList<(String, String)>),
+            }
+        };
+        PolarsNullValues(result.expect("Failure when unpacking an OCaml<NullValues> variant into PolarsNullValues (unexpected tag value"))
+    }
+}
+
+pub struct PolarsCsvEncoding(pub CsvEncoding);
+
+unsafe impl FromOCaml<CsvEncoding> for PolarsCsvEncoding {
+    fn from_ocaml(v: OCaml<CsvEncoding>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Utf8 => CsvEncoding::Utf8,
+                Lossy_utf8 => CsvEncoding::LossyUtf8,
+            }
+        };
+        PolarsCsvEncoding(result.expect("Failure when unpacking an OCaml<CsvEncoding> variant into PolarsCsvEncoding (unexpected tag value"))
+    }
+}
+
+pub struct PolarsQuoteStyle(pub QuoteStyle);
+
+unsafe impl FromOCaml<QuoteStyle> for PolarsQuoteStyle {
+    fn from_ocaml(v: OCaml<QuoteStyle>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Always => QuoteStyle::Always,
+                Necessary => QuoteStyle::Necessary,
+                Non_numeric => QuoteStyle::NonNumeric,
+            }
+        };
+        PolarsQuoteStyle(result.expect("Failure when unpacking an OCaml<QuoteStyle> variant into PolarsQuoteStyle (unexpected tag value"))
+    }
+}
+
+pub struct PolarsRowCount(pub RowCount);
+
+unsafe impl FromOCaml<(String, OCamlInt)> for PolarsRowCount {
+    fn from_ocaml(v: OCaml<(String, OCamlInt)>) -> Self {
+        let name: String = v.fst().to_rust();
+        let offset_: i64 = v.snd().to_rust();
+        let offset = match IdxSize::try_from(offset_) {
+            Ok(offset) => offset,
+            Err(_) => unsafe {
+                ocaml_failwith(&format!("Failed conversion to IdxSize {:?}", offset_))
+            },
+        };
+        PolarsRowCount(RowCount { name, offset })
+    }
+}
+
+pub struct PolarsParallelStrategy(pub ParallelStrategy);
+
+unsafe impl FromOCaml<ParallelStrategy> for PolarsParallelStrategy {
+    fn from_ocaml(v: OCaml<ParallelStrategy>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Auto => ParallelStrategy::Auto,
+                Columns => ParallelStrategy::Columns,
+                None => ParallelStrategy::None,
+                Row_groups => ParallelStrategy::RowGroups,
+            }
+        };
+        PolarsParallelStrategy(result.expect("Failure when unpacking an OCaml<ParallelStrategy> variant into PolarsParallelStrategy (unexpected tag value"))
+    }
+}
+
+pub struct PolarsIpcCompression(pub IpcCompression);
+
+unsafe impl FromOCaml<IpcCompression> for PolarsIpcCompression {
+    fn from_ocaml(v: OCaml<IpcCompression>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Lz4 => IpcCompression::LZ4,
+                Zstd => IpcCompression::ZSTD,
+            }
+        };
+        PolarsIpcCompression(result.expect("Failure when unpacking an OCaml<IpcCompression> variant into PolarsIpcCompression (unexpected tag value"))
+    }
+}
+
+pub struct PolarsParquetCompression(pub ParquetCompression);
+
+unsafe impl FromOCaml<ParquetCompression> for PolarsParquetCompression {
+    fn from_ocaml(v: OCaml<ParquetCompression>) -> Self {
+        fn level<Via: TryFrom<i64>, Level>(
+            level: Option<i64>,
+            try_new: impl FnOnce(Via) -> PolarsResult<Level>,
+        ) -> Option<Level> {
+            level.map(|level_| {
+                let level = Via::try_from(level_)
+                    .map_err(|_| format!("Invalid compression level {}", level_))
+                    .and_then(|level| try_new(level).map_err(|err| err.to_string()));
+                match level {
+                    Ok(level) => level,
+                    Err(err) => unsafe { ocaml_failwith(&err) },
+                }
+            })
+        }
+
+        let result = ocaml_unpack_variant! {
+            v => {
+                ParquetCompression::Uncompressed,
+                ParquetCompression::Snappy,
+                ParquetCompression::Gzip(gzip_level: Option<OCamlInt>) => {
+                    ParquetCompression::Gzip(level(gzip_level, GzipLevel::try_new))
+                },
+                ParquetCompression::Lzo,
+                ParquetCompression::Brotli(brotli_level: Option<OCamlInt>) => {
+                    ParquetCompression::Brotli(level(brotli_level, BrotliLevel::try_new))
+                },
+                ParquetCompression::Zstd(zstd_level: Option<OCamlInt>) => {
+                    ParquetCompression::Zstd(level(zstd_level, ZstdLevel::try_new))
+                },
+                ParquetCompression::Lz4Raw,
+            }
+        };
+        PolarsParquetCompression(result.expect("Failure when unpacking an OCaml<ParquetCompression> variant into PolarsParquetCompression (unexpected tag value"))
+    }
+}
+
+pub struct OCamlBigstring(pub Vec<u8>);
+
+unsafe impl FromOCaml<Array1<u8>> for OCamlBigstring {
+    fn from_ocaml(v: OCaml<Array1<u8>>) -> Self {
+        OCamlBigstring(v.as_slice().to_vec())
+    }
+}
+
+// Where readers get their input from: either a path to a file on disk or a
+// buffer copied out of an OCaml string, bytes or Bigstring.
+pub enum ReadSource {
+    Path(String),
+    Buffer(Vec<u8>),
+}
+
+impl ReadSource {
+    pub fn open(self) -> Result<Box<dyn MmapBytesReader>, String> {
+        match self {
+            ReadSource::Path(path) => File::open(path)
+                .map(|file| Box::new(file) as Box<dyn MmapBytesReader>)
+                .map_err(|err| err.to_string()),
+            ReadSource::Buffer(buffer) => Ok(Box::new(Cursor::new(buffer))),
+        }
+    }
+}
+
+unsafe impl FromOCaml<ReadSource> for ReadSource {
+    fn from_ocaml(v: OCaml<ReadSource>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Path(path: String) => ReadSource::Path(path),
+                String(string: OCamlBytes) => {
+                    let string: Vec<u8> = string;
+                    ReadSource::Buffer(string)
+                },
+                Bytes(bytes: OCamlBytes) => {
+                    let bytes: Vec<u8> = bytes;
+                    ReadSource::Buffer(bytes)
+                },
+                Bigstring(bigstring: Array1<u8>) => {
+                    let OCamlBigstring(bigstring) = bigstring;
+                    ReadSource::Buffer(bigstring)
+                },
+            }
+        };
+        result.expect("Failure when unpacking an OCaml<ReadSource> variant into ReadSource (unexpected tag value")
+    }
+}
+
+// Where writers send their output to: either a file created at the given path
+// or an in-memory buffer which is handed back to OCaml once writing finishes.
+pub enum WriteTarget {
+    Path(String),
+    Buffer,
+}
+
+impl WriteTarget {
+    pub fn create(self) -> Result<WriteSink, String> {
+        match self {
+            WriteTarget::Path(path) => File::create(path)
+                .map(WriteSink::File)
+                .map_err(|err| err.to_string()),
+            WriteTarget::Buffer => Ok(WriteSink::Buffer(Vec::new())),
+        }
+    }
+}
+
+unsafe impl FromOCaml<WriteTarget> for WriteTarget {
+    fn from_ocaml(v: OCaml<WriteTarget>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Path(path: String) => WriteTarget::Path(path),
+                Buffer => WriteTarget::Buffer,
+            }
+        };
+        result.expect("Failure when unpacking an OCaml<WriteTarget> variant into WriteTarget (unexpected tag value")
+    }
+}
+
+// File formats which DataFrames can be written out as one file per partition.
+#[derive(Clone, Copy)]
+pub enum FileFormat {
+    Csv,
+    Ipc,
+    Parquet,
+}
+
+impl FileFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            FileFormat::Csv => "csv",
+            FileFormat::Ipc => "ipc",
+            FileFormat::Parquet => "parquet",
+        }
+    }
+}
+
+unsafe impl FromOCaml<FileFormat> for FileFormat {
+    fn from_ocaml(v: OCaml<FileFormat>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Csv => FileFormat::Csv,
+                Ipc => FileFormat::Ipc,
+                Parquet => FileFormat::Parquet,
+            }
+        };
+        result.expect("Failure when unpacking an OCaml<FileFormat> variant into FileFormat (unexpected tag value")
+    }
+}
+
+// The structs of the Arrow C Data and Stream Interfaces.
+#[derive(Clone, Copy)]
+pub enum ArrowCStruct {
+    Schema,
+    Array,
+    Stream,
+}
+
+unsafe impl FromOCaml<ArrowCStruct> for ArrowCStruct {
+    fn from_ocaml(v: OCaml<ArrowCStruct>) -> Self {
+        let result = ocaml_unpack_polymorphic_variant! {
+            v => {
+                Schema => ArrowCStruct::Schema,
+                Array => ArrowCStruct::Array,
+                Stream => ArrowCStruct::Stream,
+            }
+        };
+        result.expect("Failure when unpacking an OCaml<ArrowCStruct> variant into ArrowCStruct (unexpected tag value")
+    }
+}
+
+pub enum WriteSink {
+    File(File),
+    Buffer(Vec<u8>),
+}
+
+impl WriteSink {
+    // Returns the written bytes if we were writing to an in-memory buffer.
+    pub fn into_buffer(self) -> Option<Vec<u8>> {
+        match self {
+            WriteSink::File(_) => None,
+            WriteSink::Buffer(buffer) => Some(buffer),
+        }
+    }
+}
+
+impl Write for WriteSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            WriteSink::File(file) => file.write(buf),
+            WriteSink::Buffer(buffer) => buffer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            WriteSink::File(file) => file.flush(),
+            WriteSink::Buffer(buffer) => buffer.flush(),
+        }
+    }
+}
+
 // Coerce<OCamlType, Via, T>, given OCamlType which can be converted into a Rust
 // type Via, will try_into() T and will raise an OCaml exception if the
 // conversion fails. For example, Coerce<OCamlInt, i64, u32> will convert an
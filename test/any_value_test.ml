@@ -0,0 +1,224 @@
+open! Core
+open! Polars
+
+let%expect_test "get_any_value" =
+  let df =
+    Data_frame.create_exn
+      [ Series.into "int" [ Some 1; None ]
+      ; Series.floato "float" [ None; Some 2.5 ]
+      ; Series.stringo "string" [ Some "a"; None ]
+      ; Series.date_option "date" [ Some (Date.of_string "2023-01-02"); None ]
+      ; Series.datetime
+          "datetime"
+          (List.map
+             [ "2023-01-02 03:04:05"; "2023-01-03 00:00:00" ]
+             ~f:Common.Naive_datetime.of_string)
+      ; Series.create (List Int64) "list" [ [ 1; 2 ]; [] ]
+      ; Series.createo
+          (Struct (Field ("x", Int64, Field ("label", Utf8, Empty))))
+          "struct"
+          [ Some (1, ("a", ())); None ]
+      ; Series.create
+          (Duration Milliseconds)
+          "duration"
+          [ Time_ns.Span.of_sec 1.; Time_ns.Span.of_int_ms 1500 ]
+      ]
+  in
+  List.iter [ 0; 1 ] ~f:(fun i ->
+    List.iter (Data_frame.get_column_names df) ~f:(fun name ->
+      let value = Data_frame.get_any_value_exn df ~name i in
+      print_s [%message "" (name : string) (value : Any_value.t)]));
+  [%expect
+    {|
+    ((name int) (value (Int64 1)))
+    ((name float) (value Null))
+    ((name string) (value (Utf8 a)))
+    ((name date) (value (Date 2023-01-02)))
+    ((name datetime) (value (Datetime ("2023-01-02 03:04:05" Milliseconds ()))))
+    ((name list) (value (List ((Int64 1) (Int64 2)))))
+    ((name struct) (value (Struct ((x (Int64 1)) (label (Utf8 a))))))
+    ((name duration) (value (Duration (1s Milliseconds))))
+    ((name int) (value Null))
+    ((name float) (value (Float64 2.5)))
+    ((name string) (value Null))
+    ((name date) (value Null))
+    ((name datetime) (value (Datetime ("2023-01-03 00:00:00" Milliseconds ()))))
+    ((name list) (value (List ())))
+    ((name struct) (value Null))
+    ((name duration) (value (Duration (1.5s Milliseconds)))) |}];
+  Data_frame.get_any_value df ~name:"missing" 0
+  |> [%sexp_of: (Any_value.t, string) Result.t]
+  |> print_s;
+  [%expect {| (Error "not found: missing") |}];
+  let series = Data_frame.column_exn df ~name:"int" in
+  Series.get_any_value series 2 |> [%sexp_of: (Any_value.t, string) Result.t] |> print_s;
+  [%expect {| (Error "index 2 is out of bounds for sequence of size 2") |}];
+  Series.get_any_value series (-1)
+  |> [%sexp_of: (Any_value.t, string) Result.t]
+  |> print_s;
+  [%expect {| (Error "index -1 is out of bounds") |}]
+;;
+
+let%expect_test "get_any_value of values which don't fit in an OCaml int" =
+  let df =
+    Data_frame.create_exn [ Series.int "x" [ Int.max_value ] ]
+    |> Data_frame.select_exn
+         ~exprs:
+           Expr.
+             [ col "x" * int 2 |> alias ~name:"int64"
+             ; cast (col "x" * int 2) ~to_:UInt64 |> alias ~name:"uint64"
+             ; cast (col "x") ~to_:(Duration Milliseconds) |> alias ~name:"duration"
+             ]
+  in
+  List.iter (Data_frame.get_column_names df) ~f:(fun name ->
+    Data_frame.get_any_value df ~name 0
+    |> [%sexp_of: (Any_value.t, string) Result.t]
+    |> print_s);
+  [%expect
+    {|
+    (Error "9223372036854775806 does not fit in an OCaml int")
+    (Error "9223372036854775806 does not fit in an OCaml int")
+    (Error "duration 4611686018427387903 ms does not fit in a Time_ns.Span.t") |}];
+  Data_frame.get_row df 0 |> [%sexp_of: (Any_value.t array, string) Result.t] |> print_s;
+  [%expect {| (Error "9223372036854775806 does not fit in an OCaml int") |}]
+;;
+
+let%expect_test "of_rows" =
+  Data_frame.of_rows_exn
+    Any_value.
+      [ [ Int64 1; Utf8 "a"; Null ]; [ Null; Utf8 "b"; List [ Float64 1.5 ] ] ]
+  |> Data_frame.print;
+  [%expect
+    {|
+    shape: (2, 3)
+    ┌──────────┬──────────┬───────────┐
+    │ column_0 ┆ column_1 ┆ column_2  │
+    │ ---      ┆ ---      ┆ ---       │
+    │ i64      ┆ str      ┆ list[f64] │
+    ╞══════════╪══════════╪═══════════╡
+    │ 1        ┆ a        ┆ null      │
+    │ null     ┆ b        ┆ [1.5]     │
+    └──────────┴──────────┴───────────┘ |}];
+  let schema =
+    Schema.create [ "id", UInt8; "time", Datetime (Milliseconds, None); "score", Float64 ]
+  in
+  let time =
+    Any_value.Datetime
+      (Common.Naive_datetime.of_string "2023-01-01 00:00:00", Nanoseconds, None)
+  in
+  Data_frame.of_rows_exn
+    ~schema
+    Any_value.[ [ Int64 1; time; Int64 3 ]; [ UInt8 2; Null; Float64 0.5 ] ]
+  |> Data_frame.print;
+  [%expect
+    {|
+    shape: (2, 3)
+    ┌─────┬─────────────────────┬───────┐
+    │ id  ┆ time                ┆ score │
+    │ --- ┆ ---                 ┆ ---   │
+    │ u8  ┆ datetime[ms]        ┆ f64   │
+    ╞═════╪═════════════════════╪═══════╡
+    │ 1   ┆ 2023-01-01 00:00:00 ┆ 3.0   │
+    │ 2   ┆ null                ┆ 0.5   │
+    └─────┴─────────────────────┴───────┘ |}];
+  List.iter
+    Any_value.
+      [ [ [ Int64 1; Null; Null ]; [ Int64 300; Null; Null ] ]
+      ; [ [ Int64 1; Null; Utf8 "x" ] ]
+      ; [ [ Int64 1; Null ] ]
+      ; [ [ Int64 1; Null; Null ]; [ UInt8 300; Null; Null ] ]
+      ; [ [ Int64 1
+          ; Datetime
+              ( Common.Naive_datetime.of_string "2023-01-01 00:00:00"
+              , Milliseconds
+              , Some "Not/A_Zone" )
+          ; Null
+          ]
+        ]
+      ]
+    ~f:(fun rows ->
+      match Data_frame.of_rows ~schema rows with
+      | Ok _ -> print_endline "ok"
+      | Error error -> print_endline error);
+  [%expect
+    {|
+    row 1, column id: cannot convert 300 of type i64 to u8
+    row 0, column score: cannot convert "x" of type str to f64
+    row 0 has 2 values, expected 3
+    row 1, column id: 300 is out of range for u8
+    row 0, column time: unable to parse time zone: 'Not/A_Zone' |}];
+  let schema =
+    Schema.create
+      [ "time", Datetime (Nanoseconds, None)
+      ; "list", List UInt8
+      ; "struct", Struct [ "a", Int64; "b", Utf8 ]
+      ]
+  in
+  let time =
+    Any_value.Datetime
+      (Common.Naive_datetime.of_string "3000-01-01 00:00:00", Milliseconds, None)
+  in
+  List.iter
+    Any_value.
+      [ [ [ Null; List [ UInt8 1 ]; Struct [ "a", Null; "b", Null ] ] ]
+      ; [ [ time; Null; Null ] ]
+      ; [ [ Null; Utf8 "x"; Null ] ]
+      ; [ [ Null; Null; Int64 1 ] ]
+      ]
+    ~f:(fun rows ->
+      match Data_frame.of_rows ~schema rows with
+      | Ok _ -> print_endline "ok"
+      | Error error -> print_endline error);
+  [%expect
+    {|
+    ok
+    row 0, column time: 32503680000000ms is out of range for datetime[ns]
+    row 0, column list: cannot convert "x" of type str to list[u8]
+    row 0, column struct: cannot convert 1 of type i64 to struct[2] |}]
+;;
+
+let%expect_test "get_row and iter_rows" =
+  let df =
+    Data_frame.create_exn
+      [ Series.into "id" [ Some 1; Some 2; None; Some 4; Some 5 ]
+      ; Series.stringo "label" [ Some "a"; None; Some "c"; Some "d"; Some "e" ]
+      ]
+  in
+  Data_frame.get_row_exn df 1 |> [%sexp_of: Any_value.t array] |> print_s;
+  [%expect {| ((Int64 2) Null) |}];
+  Data_frame.get_row df 5 |> [%sexp_of: (Any_value.t array, string) Result.t] |> print_s;
+  [%expect {| (Error "rows 5..6 are out of bounds for a DataFrame with 5 rows") |}];
+  Data_frame.iter_rows ~chunk_size:2 df
+  |> Sequence.iter ~f:(fun row -> print_s [%sexp (row : Any_value.t array)]);
+  [%expect
+    {|
+    ((Int64 1) (Utf8 a))
+    ((Int64 2) Null)
+    (Null (Utf8 c))
+    ((Int64 4) (Utf8 d))
+    ((Int64 5) (Utf8 e)) |}];
+  let row_type : _ Row_type.t = [ Int64; Utf8 ] in
+  Data_frame.get_typed_row row_type df 2
+  |> [%sexp_of: int option * (string option * unit)]
+  |> print_s;
+  [%expect {| (() ((c) ())) |}];
+  Data_frame.iter_typed_rows ~chunk_size:3 row_type df
+  |> Sequence.fold ~init:0 ~f:(fun sum (id, _) -> sum + Option.value id ~default:0)
+  |> printf "%d\n";
+  [%expect {| 12 |}];
+  let label : string Data_type.Typed.t =
+    Custom { data_type = Utf8; f = String.uppercase; f_inverse = String.lowercase }
+  in
+  Data_frame.iter_typed_rows Row_type.[ Int64; label ] df
+  |> Sequence.to_list
+  |> List.map ~f:(fun (_, (label, ())) -> label)
+  |> [%sexp_of: string option list]
+  |> print_s;
+  [%expect {| ((A) () (C) (D) (E)) |}];
+  Expect_test_helpers_core.require_does_raise [%here] (fun () ->
+    Data_frame.get_typed_row Row_type.[ Int64 ] df 0);
+  [%expect
+    {|
+    (Failure
+     "Polars panicked: Row type has 1 columns but the DataFrame has 2\nbacktrace not captured") |}]
+;;
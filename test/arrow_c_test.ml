@@ -0,0 +1,83 @@
+open! Core
+open Polars
+
+let%expect_test "series round trip" =
+  let series = Series.into "a" [ Some 1; None; Some 3 ] in
+  Arrow_c.with_ `Schema ~f:(fun schema ->
+    Arrow_c.with_ `Array ~f:(fun array ->
+      Series.export_to_c_exn series ~schema ~array;
+      Series.import_from_c_exn ~schema ~array |> Series.print));
+  [%expect
+    {|
+    shape: (3,)
+    Series: 'a' [i64]
+    [
+    	1
+    	null
+    	3
+    ] |}]
+;;
+
+let data_frame () =
+  let data_frame =
+    Data_frame.create_exn
+      [ Series.into "a" [ Some 1; None; Some 3 ]
+      ; Series.stringo "b" [ Some "x"; Some "y"; None ]
+      ]
+  in
+  Data_frame.vstack_exn
+    data_frame
+    ~other:
+      (Data_frame.create_exn
+         [ Series.into "a" [ None; Some 5 ]; Series.stringo "b" [ None; Some "z" ] ]);
+  data_frame
+;;
+
+let%expect_test "data frame round trip" =
+  Arrow_c.with_ `Schema ~f:(fun schema ->
+    Arrow_c.with_ `Array ~f:(fun array ->
+      Data_frame.export_to_c_exn (data_frame ()) ~schema ~array;
+      Data_frame.import_from_c_exn ~schema ~array |> Data_frame.print));
+  [%expect
+    {|
+    shape: (5, 2)
+    ┌──────┬──────┐
+    │ a    ┆ b    │
+    │ ---  ┆ ---  │
+    │ i64  ┆ str  │
+    ╞══════╪══════╡
+    │ 1    ┆ x    │
+    │ null ┆ y    │
+    │ 3    ┆ null │
+    │ null ┆ null │
+    │ 5    ┆ z    │
+    └──────┴──────┘ |}];
+  Arrow_c.with_ `Stream ~f:(fun stream ->
+    Data_frame.export_stream_to_c_exn (data_frame ()) ~stream;
+    Data_frame.import_stream_from_c_exn ~stream |> Data_frame.print);
+  [%expect
+    {|
+    shape: (5, 2)
+    ┌──────┬──────┐
+    │ a    ┆ b    │
+    │ ---  ┆ ---  │
+    │ i64  ┆ str  │
+    ╞══════╪══════╡
+    │ 1    ┆ x    │
+    │ null ┆ y    │
+    │ 3    ┆ null │
+    │ null ┆ null │
+    │ 5    ┆ z    │
+    └──────┴──────┘ |}]
+;;
+
+let%expect_test "freeing unimported exports" =
+  (* The exported data is released when the structs are freed. *)
+  Arrow_c.with_ `Schema ~f:(fun schema ->
+    Arrow_c.with_ `Array ~f:(fun array ->
+      Data_frame.export_to_c_exn (data_frame ()) ~schema ~array));
+  Arrow_c.with_ `Stream ~f:(fun stream ->
+    Data_frame.export_stream_to_c_exn (data_frame ()) ~stream);
+  Data_frame.import_from_c ~schema:0n ~array:0n |> Result.is_ok |> printf "%b\n";
+  [%expect {| false |}]
+;;
@@ -0,0 +1,122 @@
+open! Core
+open Polars
+
+let print_bigarray ?validity array ~to_string =
+  List.init (Bigarray.Array1.dim array) ~f:(fun i ->
+    match validity with
+    | Some validity when validity.{i} = 0 -> "null"
+    | _ -> to_string array.{i})
+  |> String.concat ~sep:" "
+  |> print_endline
+;;
+
+let%expect_test "to_bigarray" =
+  let values, validity =
+    Series.float "x" [ 1.; 2.5; -3. ] |> Series.to_bigarray_exn ~kind:Float64
+  in
+  print_bigarray values ~to_string:Float.to_string;
+  print_s [%message (Option.is_some validity : bool)];
+  [%expect {|
+    1. 2.5 -3.
+    ("Option.is_some validity" false) |}];
+  let values, validity =
+    Series.into "x" [ Some 1; None; Some 3 ] |> Series.to_bigarray_exn ~kind:Int32
+  in
+  print_bigarray ?validity values ~to_string:Int32.to_string;
+  Option.iter validity ~f:(print_bigarray ~to_string:Int.to_string);
+  [%expect {|
+    1 null 3
+    1 0 1 |}];
+  let print_is_ok ?allow_lossy series ~kind =
+    Series.to_bigarray ?allow_lossy series ~kind |> Result.is_ok |> printf "%b\n"
+  in
+  print_is_ok (Series.int "x" [ 1; 300 ]) ~kind:Int8_signed;
+  print_is_ok (Series.string "x" [ "a" ]) ~kind:Float64;
+  [%expect {|
+    false
+    false |}];
+  (* Casts that lose information need [allow_lossy]. *)
+  print_is_ok (Series.float "x" [ 1.; -2. ]) ~kind:Int64;
+  print_is_ok (Series.float "x" [ 1.; Float.nan ]) ~kind:Float32;
+  print_is_ok (Series.float "x" [ 1.5 ]) ~kind:Int64;
+  print_is_ok (Series.int "x" [ (1 lsl 53) + 1 ]) ~kind:Float64;
+  [%expect {|
+    true
+    true
+    false
+    false |}];
+  Series.to_bigarray (Series.float "x" [ 1.5 ]) ~kind:Int64
+  |> Result.iter_error ~f:print_endline;
+  [%expect {| casting x from f64 to i64 would lose information |}];
+  let values, (_ : _ option) =
+    Series.float "x" [ 1.5; -2.5 ] |> Series.to_bigarray_exn ~allow_lossy:true ~kind:Int64
+  in
+  print_bigarray values ~to_string:Int64.to_string;
+  [%expect {| 1 -2 |}]
+;;
+
+let%expect_test "of_bigarray and of_float_array" =
+  let values = Bigarray.Array1.of_array Int16_signed C_layout [| 1; -2; 3 |] in
+  let validity = Bigarray.Array1.of_array Int8_unsigned C_layout [| 1; 0; 1 |] in
+  Series.of_bigarray_exn "x" values |> Series.print;
+  Series.of_bigarray_exn ~validity "y" values |> Series.print;
+  [%expect
+    {|
+    shape: (3,)
+    Series: 'x' [i16]
+    [
+    	1
+    	-2
+    	3
+    ]
+    shape: (3,)
+    Series: 'y' [i16]
+    [
+    	1
+    	null
+    	3
+    ] |}];
+  let values = Bigarray.Array1.of_array Float32 C_layout [| 0.5; 1.5 |] in
+  let values', validity' =
+    Series.of_bigarray_exn "x" values |> Series.to_bigarray_exn ~kind:Float32
+  in
+  print_s [%message (Poly.equal values values' : bool) (Option.is_none validity' : bool)];
+  [%expect {| (("Poly.equal values values'" true) ("Option.is_none validity'" true)) |}];
+  let validity = Bigarray.Array1.create Int8_unsigned C_layout 1 in
+  Series.of_bigarray ~validity "x" values |> Result.iter_error ~f:print_endline;
+  [%expect {| Validity mask has length 1, expected 2 |}];
+  Series.of_float_array "x" [| 1.; Float.nan; -0.25 |] |> Series.print;
+  Series.of_float_array "empty" [||] |> Series.print;
+  [%expect
+    {|
+    shape: (3,)
+    Series: 'x' [f64]
+    [
+    	1.0
+    	NaN
+    	-0.25
+    ]
+    shape: (0,)
+    Series: 'empty' [f64]
+    [
+    ] |}]
+;;
+
+let%expect_test "create_array with flat float arrays" =
+  Series.create_array Float64 "x" [| 1.5; -2.; Float.infinity |] |> Series.print;
+  Series.create_array Float32 "y" [| 0.25 |] |> Series.print;
+  [%expect
+    {|
+    shape: (3,)
+    Series: 'x' [f64]
+    [
+    	1.5
+    	-2.0
+    	inf
+    ]
+    shape: (1,)
+    Series: 'y' [f32]
+    [
+    	0.25
+    ] |}]
+;;
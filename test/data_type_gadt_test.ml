@@ -18,7 +18,95 @@ let%expect_test "unit tests" =
   [%expect
     {|
     (Failure
-     "Polars panicked: data types don't match: invalid series dtype: expected `Float64`, got `i64`\nbacktrace not captured") |}]
+     "Polars panicked: data types don't match: invalid series dtype: expected `Float64`, got `i64`\nbacktrace not captured") |}];
+  (* Durations which don't fit in a Time_ns.Span.t once converted to nanoseconds
+     should raise rather than overflow *)
+  let series =
+    Data_frame.create_exn [ Series.int "duration" [ Int.max_value ] ]
+    |> Data_frame.select_exn
+         ~exprs:Expr.[ cast (col "duration") ~to_:(Duration Milliseconds) ]
+    |> Data_frame.column_exn ~name:"duration"
+  in
+  Expect_test_helpers_core.require_does_raise [%here] (fun () ->
+    Series.to_list (Duration Milliseconds) series);
+  [%expect
+    {|
+    (Failure
+     "Polars panicked: duration 4611686018427387903 ms does not fit in a Time_ns.Span.t\nbacktrace not captured") |}];
+  Expect_test_helpers_core.require_does_raise [%here] (fun () ->
+    Series.get (Duration Milliseconds) series 0);
+  [%expect
+    {|
+    (Failure
+     "Polars panicked: duration 4611686018427387903 ms does not fit in a Time_ns.Span.t\nbacktrace not captured") |}];
+  (* Polars times must be before midnight, so unlike Time_ns.Ofday.t they can't be
+     the start of the next day *)
+  Expect_test_helpers_core.require_does_raise [%here] (fun () ->
+    Series.create Time "time" [ Time_ns.Ofday.start_of_next_day ]);
+  [%expect
+    {|
+    (Failure
+     "Polars panicked: time of day 86400000000000ns is outside of [0ns, 86400000000000ns)\nbacktrace not captured") |}];
+  Expect_test_helpers_core.require_does_raise [%here] (fun () ->
+    Expr.lit Time Time_ns.Ofday.start_of_next_day);
+  [%expect
+    {|
+    (Failure
+     "Polars panicked: time of day 86400000000000ns is outside of [0ns, 86400000000000ns)\nbacktrace not captured") |}];
+  Series.create
+    Time
+    "time"
+    [ Time_ns.Ofday.start_of_day; Time_ns.Ofday.approximate_end_of_day ]
+  |> Series.to_list Time
+  |> [%sexp_of: Time_ns.Ofday.t list]
+  |> print_s;
+  [%expect {| (00:00:00.000000000 23:59:59.999999999) |}]
+;;
+
+let nanoseconds_per_unit : Data_type.Time_unit.t -> int = function
+  | Nanoseconds -> 1
+  | Microseconds -> 1_000
+  | Milliseconds -> 1_000_000
+;;
+
+(* Generates nanosecond counts which can be represented exactly with the given time
+   unit, staying well within the range of 64bit nanosecond timestamps. *)
+let nanoseconds_generator time_unit =
+  Base_quickcheck.Generator.int_inclusive (Int.min_value / 2) (Int.max_value / 2)
+  |> Base_quickcheck.Generator.map ~f:(fun ns ->
+    Int.round_down ns ~to_multiple_of:(nanoseconds_per_unit time_unit))
+;;
+
+let naive_date_to_string naive_date =
+  Common.Naive_datetime.of_naive_date naive_date |> Common.Naive_datetime.to_string
+;;
+
+type point =
+  { x : int
+  ; label : string
+  }
+[@@deriving sexp_of]
+
+let%expect_test "struct series of records" =
+  let point : point Data_type.Typed.t =
+    Custom
+      { data_type = Struct (Field ("x", Int64, Field ("label", Utf8, Empty)))
+      ; f = (fun (x, (label, ())) -> { x; label })
+      ; f_inverse = (fun { x; label } -> x, (label, ()))
+      }
+  in
+  let series =
+    Series.createo
+      point
+      "points"
+      [ Some { x = 1; label = "a" }; None; Some { x = 2; label = "b" } ]
+  in
+  Series.dtype series |> [%sexp_of: Data_type.t] |> print_s;
+  [%expect {| (Struct ((x Int64) (label Utf8))) |}];
+  Series.to_option_list point series |> [%sexp_of: point option list] |> print_s;
+  [%expect {| ((((x 1) (label a))) () (((x 2) (label b)))) |}];
+  Series.get point series 2 |> [%sexp_of: point option] |> print_s;
+  [%expect {| (((x 2) (label b))) |}]
 ;;
 
 (* TODO: perhaps these things should be bundled in a module and there should be a single function like:
@@ -57,9 +145,35 @@ let rec value_generator : type a. a Data_type.Typed.t -> a Quickcheck.Generator.
     |> (* Core.String doesn't have a [is_valid_utf_8] function :( *)
     Generator.filter ~f:Stdlib.String.is_valid_utf_8
   | Binary -> Generator.string
+  | Date -> Date.quickcheck_generator |> Generator.map ~f:Common.Naive_date.of_date
+  | Datetime (time_unit, _) ->
+    nanoseconds_generator time_unit
+    |> Generator.map ~f:(fun ns ->
+      Time_ns.of_int_ns_since_epoch ns |> Common.Naive_datetime.of_time_ns_exn)
+  | Duration time_unit ->
+    nanoseconds_generator time_unit |> Generator.map ~f:Time_ns.Span.of_int_ns
+  | Time ->
+    Generator.int_inclusive 0 (Time_ns.Span.to_int_ns Time_ns.Span.day - 1)
+    |> Generator.map ~f:(fun ns ->
+      Time_ns.Ofday.of_span_since_start_of_day_exn (Time_ns.Span.of_int_ns ns))
   | List t -> value_generator t |> Generator.list
+  | Struct fields -> fields_generator fields
+  | Decimal (precision, _) ->
+    let max =
+      Option.value_map precision ~default:Int.max_value ~f:(fun precision ->
+        Int.(10 ** precision) - 1)
+    in
+    Generator.int_inclusive (-max) max
+  | Array (t, width) -> value_generator t |> Generator.list_with_length ~length:width
   | Custom { data_type; f; f_inverse = _ } ->
     value_generator data_type |> Generator.map ~f
+
+and fields_generator : type a. a Data_type.Typed.fields -> a Quickcheck.Generator.t =
+  fun (type a) (fields : a Data_type.Typed.fields) : a Quickcheck.Generator.t ->
+  match fields with
+  | Empty -> Base_quickcheck.Generator.unit
+  | Field (_, t, rest) ->
+    Base_quickcheck.Generator.both (value_generator t) (fields_generator rest)
 ;;
 
 let rec value_shrinker : type a. a Data_type.Typed.t -> a Quickcheck.Shrinker.t =
@@ -79,10 +193,24 @@ let rec value_shrinker : type a. a Data_type.Typed.t -> a Quickcheck.Shrinker.t
   | Float64 -> Shrinker.float
   | Utf8 -> Shrinker.string
   | Binary -> Shrinker.string
+  | Date -> Shrinker.atomic
+  | Datetime _ -> Shrinker.atomic
+  | Duration _ -> Shrinker.atomic
+  | Time -> Shrinker.atomic
   | List t ->
     value_shrinker t |> Shrinker.list |> Shrinker.filter ~f:(Fn.non List.is_empty)
+  | Struct fields -> fields_shrinker fields
+  | Decimal _ -> Shrinker.int
+  | Array _ -> Shrinker.atomic
   | Custom { data_type; f; f_inverse } ->
     value_shrinker data_type |> Shrinker.map ~f ~f_inverse
+
+and fields_shrinker : type a. a Data_type.Typed.fields -> a Quickcheck.Shrinker.t =
+  fun (type a) (fields : a Data_type.Typed.fields) : a Quickcheck.Shrinker.t ->
+  match fields with
+  | Empty -> Base_quickcheck.Shrinker.unit
+  | Field (_, t, rest) ->
+    Base_quickcheck.Shrinker.both (value_shrinker t) (fields_shrinker rest)
 ;;
 
 let rec value_to_sexp : type a. a Data_type.Typed.t -> a -> Sexp.t =
@@ -101,10 +229,28 @@ let rec value_to_sexp : type a. a Data_type.Typed.t -> a -> Sexp.t =
   | Float64 -> [%sexp_of: float] a
   | Utf8 -> [%sexp_of: string] a
   | Binary -> [%sexp_of: string] a
+  | Date -> [%sexp_of: string] (naive_date_to_string a)
+  | Datetime _ -> [%sexp_of: string] (Common.Naive_datetime.to_string a)
+  | Duration _ -> [%sexp_of: Time_ns.Span.t] a
+  | Time -> [%sexp_of: Time_ns.Ofday.t] a
   | List t ->
     let sexp_of_value = value_to_sexp t in
     [%sexp_of: value list] a
+  | Struct fields -> [%sexp_of: Sexp.t list] (fields_to_sexps fields a)
+  | Decimal _ -> [%sexp_of: int] a
+  | Array (t, _) ->
+    let sexp_of_value = value_to_sexp t in
+    [%sexp_of: value list] a
   | Custom { data_type; f = _; f_inverse } -> value_to_sexp data_type (f_inverse a)
+
+and fields_to_sexps : type a. a Data_type.Typed.fields -> a -> Sexp.t list =
+  fun (type a) (fields : a Data_type.Typed.fields) (a : a) ->
+  match fields with
+  | Empty -> []
+  | Field (name, t, rest) ->
+    let value, rest_values = a in
+    [%sexp (name : string), (value_to_sexp t value : Sexp.t)]
+    :: fields_to_sexps rest rest_values
 ;;
 
 let rec value_compare : type a. a Data_type.Typed.t -> a -> a -> int =
@@ -123,9 +269,28 @@ let rec value_compare : type a. a Data_type.Typed.t -> a -> a -> int =
   | Float64 -> [%compare: float] a b
   | Utf8 -> [%compare: string] a b
   | Binary -> [%compare: string] a b
+  | Date -> Comparable.lift [%compare: string] ~f:naive_date_to_string a b
+  | Datetime _ ->
+    Comparable.lift [%compare: Time_ns.t] ~f:Common.Naive_datetime.to_time_ns a b
+  | Duration _ -> [%compare: Time_ns.Span.t] a b
+  | Time -> [%compare: Time_ns.Ofday.t] a b
   | List t -> List.compare (value_compare t) a b
+  | Struct fields -> fields_compare fields a b
+  | Decimal _ -> [%compare: int] a b
+  | Array (t, _) -> List.compare (value_compare t) a b
   | Custom { data_type; f = _; f_inverse } ->
     Comparable.lift (value_compare data_type) ~f:f_inverse a b
+
+and fields_compare : type a. a Data_type.Typed.fields -> a -> a -> int =
+  fun (type a) (fields : a Data_type.Typed.fields) (a : a) (b : a) ->
+  match fields with
+  | Empty -> 0
+  | Field (_, t, rest) ->
+    let value_a, rest_a = a in
+    let value_b, rest_b = b in
+    (match value_compare t value_a value_b with
+     | 0 -> fields_compare rest rest_a rest_b
+     | n -> n)
 ;;
 
 module Series_create = struct
@@ -200,6 +365,18 @@ let%expect_test "Series.create and Series.create' doesn't raise" =
         assert (value_equal value (Series.get_exn data_type series i))))
 ;;
 
+(* Series.create_array receives a flat float array for Float32 and Float64, and a
+   regular boxed array for every other data type. *)
+let%expect_test "Series.create_array doesn't raise" =
+  Base_quickcheck.Test.run_exn
+    (module Series_create)
+    ~f:(fun (Series_create.Args (data_type, values) as args) ->
+      let series = Series.create_array data_type "series_name" (Array.of_list values) in
+      let values' = Series.to_list data_type series in
+      let args' = Series_create.Args (data_type, values') in
+      [%test_result: Series_create.t] ~expect:args' args)
+;;
+
 (* TODO: there's a *lot* of duplication with the Series_create module; perhaps
    functorizing this would clean things up... *)
 module Series_createo = struct
@@ -221,3 +221,172 @@ let%expect_test "take" =
     │ 6.0 ┆ 2   │
     └─────┴─────┘ |}]
 ;;
+
+let%expect_test "categorical columns and the global string cache" =
+  let venues venues =
+    Data_frame.create_exn Series.[ string "venue" venues ]
+    |> Data_frame.with_columns_exn ~exprs:Expr.[ col "venue" |> cast ~to_:Categorical ]
+  in
+  Data_frame.concat [ venues [ "NYSE"; "LSE" ]; venues [ "LSE"; "TSE" ] ]
+  |> Result.is_ok
+  |> [%sexp_of: bool]
+  |> print_s;
+  [%expect {| false |}];
+  let df =
+    String_cache.with_ ~f:(fun () ->
+      Data_frame.concat_exn [ venues [ "NYSE"; "LSE" ]; venues [ "LSE"; "TSE" ] ])
+  in
+  print_s [%message (String_cache.is_enabled () : bool)];
+  [%expect {| ("String_cache.is_enabled ()" false) |}];
+  Data_frame.with_columns_exn
+    df
+    ~exprs:Expr.[ col "venue" |> cast ~to_:Utf8 |> alias ~name:"as_string" ]
+  |> Data_frame.print;
+  [%expect
+    {|
+    shape: (4, 2)
+    ┌───────┬───────────┐
+    │ venue ┆ as_string │
+    │ ---   ┆ ---       │
+    │ cat   ┆ str       │
+    ╞═══════╪═══════════╡
+    │ NYSE  ┆ NYSE      │
+    │ LSE   ┆ LSE       │
+    │ LSE   ┆ LSE       │
+    │ TSE   ┆ TSE       │
+    └───────┴───────────┘ |}]
+;;
+
+let%expect_test "fixed-size arrays" =
+  let embedding : float list Data_type.Typed.t = Array (Float64, 3) in
+  let series =
+    Series.createo
+      embedding
+      "embedding"
+      [ Some [ 1.; 2.; 3. ]; None; Some [ 3.; 3.; 1. ] ]
+  in
+  print_s
+    [%message
+      ""
+        ~dtype:(Series.dtype series : Data_type.t)
+        ~values:(Series.to_option_list embedding series : float list option list)];
+  [%expect {| ((dtype (Array Float64 3)) (values ((1 2 3) () (3 3 1)))) |}];
+  Expect_test_helpers_core.require_does_raise [%here] (fun () ->
+    Series.create embedding "embedding" [ [ 1.; 2. ] ]);
+  [%expect
+    {|
+    (Failure
+     "Polars panicked: expected arrays of width 3, got 2 values\nbacktrace not captured") |}];
+  let df =
+    Data_frame.create_exn
+      [ Series.create embedding "embedding" [ [ 1.; 2.; 3. ]; [ 3.; 3.; 1. ] ] ]
+    |> Data_frame.select_exn
+         ~exprs:
+           Expr.
+             [ col "embedding" |> Arr.sum |> alias ~name:"sum"
+             ; col "embedding" |> Arr.min |> alias ~name:"min"
+             ; col "embedding" |> Arr.max |> alias ~name:"max"
+             ; col "embedding" |> Arr.unique ~maintain_order:true |> alias ~name:"unique"
+             ; col "embedding" |> Arr.to_list |> alias ~name:"to_list"
+             ]
+  in
+  let column data_type name =
+    Data_frame.column_exn df ~name |> Series.to_list data_type
+  in
+  print_s
+    [%message
+      ""
+        ~sum:(column Float64 "sum" : float list)
+        ~min:(column Float64 "min" : float list)
+        ~max:(column Float64 "max" : float list)
+        ~unique:(column (List Float64) "unique" : float list list)
+        ~to_list:(column (List Float64) "to_list" : float list list)];
+  [%expect
+    {|
+    ((sum (6 7)) (min (1 1)) (max (3 3)) (unique ((1 2 3) (3 1)))
+     (to_list ((1 2 3) (3 3 1)))) |}]
+;;
+
+let%expect_test "eager select, filter, with_columns and groupby" =
+  let df =
+    Data_frame.create_exn
+      [ Series.string "name" [ "a"; "b"; "a"; "c" ]; Series.int "value" [ 1; 2; 3; 4 ] ]
+  in
+  Data_frame.filter_exn df ~predicate:Expr.(col "value" > int 1)
+  |> Data_frame.with_columns_exn ~exprs:Expr.[ col "value" * int 10 |> alias ~name:"x10" ]
+  |> Data_frame.print;
+  [%expect
+    {|
+    shape: (3, 3)
+    ┌──────┬───────┬─────┐
+    │ name ┆ value ┆ x10 │
+    │ ---  ┆ ---   ┆ --- │
+    │ str  ┆ i64   ┆ i64 │
+    ╞══════╪═══════╪═════╡
+    │ b    ┆ 2     ┆ 20  │
+    │ a    ┆ 3     ┆ 30  │
+    │ c    ┆ 4     ┆ 40  │
+    └──────┴───────┴─────┘ |}];
+  Data_frame.groupby_exn df ~by:Expr.[ col "name" ] ~agg:Expr.[ col "value" |> sum ]
+  |> Data_frame.print;
+  [%expect
+    {|
+    shape: (3, 2)
+    ┌──────┬───────┐
+    │ name ┆ value │
+    │ ---  ┆ ---   │
+    │ str  ┆ i64   │
+    ╞══════╪═══════╡
+    │ a    ┆ 4     │
+    │ b    ┆ 2     │
+    │ c    ┆ 4     │
+    └──────┴───────┘ |}];
+  (match Data_frame.select df ~exprs:Expr.[ col "missing" ] with
+   | Ok _ -> print_endline "ok"
+   | Error error -> String.split_lines error |> List.hd_exn |> print_endline);
+  [%expect {| not found: missing |}]
+;;
+
+let%expect_test "decimal sum, mean and cast" =
+  (* Prices in cents, i.e. 19.99, 2.50 and 10.01 *)
+  let price : int Data_type.Typed.t = Decimal (Some 10, 2) in
+  let mean_price : int Data_type.Typed.t = Decimal (Some 14, 6) in
+  let df =
+    Data_frame.create_exn
+      [ Series.string "shop" [ "a"; "b"; "a" ]
+      ; Series.create price "price" [ 1999; 250; 1001 ]
+      ]
+  in
+  let print_prices data_type df =
+    let series = Data_frame.column_exn df ~name:"price" in
+    print_s
+      [%message
+        ""
+          ~dtype:(Series.dtype series : Data_type.t)
+          ~values:(Series.to_list data_type series : int list)]
+  in
+  Data_frame.select_exn df ~exprs:Expr.[ col "price" |> sum ] |> print_prices price;
+  [%expect {| ((dtype (Decimal (10) (2))) (values (3250))) |}];
+  Data_frame.select_exn df ~exprs:Expr.[ col "price" |> decimal_mean ]
+  |> print_prices mean_price;
+  [%expect {| ((dtype (Decimal (14) (6))) (values (10833333))) |}];
+  Data_frame.groupby_exn
+    ~is_stable:true
+    df
+    ~by:Expr.[ col "shop" ]
+    ~agg:Expr.[ col "price" |> decimal_mean ]
+  |> print_prices mean_price;
+  [%expect {| ((dtype (Decimal (14) (6))) (values (15000000 2500000))) |}];
+  Data_frame.select_exn df ~exprs:Expr.[ col "price" |> cast ~to_:Int64 ]
+  |> print_prices Int64;
+  [%expect {| ((dtype Int64) (values (19 2 10))) |}];
+  (* The mean's precision would exceed Decimal's maximum of 38 digits. *)
+  let df =
+    Data_frame.create_exn [ Series.create (Decimal (Some 36, 2)) "price" [ 1999 ] ]
+  in
+  (match Data_frame.select df ~exprs:Expr.[ col "price" |> decimal_mean ] with
+   | Ok _ -> print_endline "ok"
+   | Error error -> String.split_lines error |> List.hd_exn |> print_endline);
+  [%expect
+    {| decimal_mean of price would need precision 40, more than the maximum of 38 |}]
+;;
@@ -0,0 +1,755 @@
+open! Core
+open Polars
+
+let%expect_test "read_csv options" =
+  Filename_extended.with_temp_dir "polars-ocaml" "csv" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "semicolon.csv" in
+    Out_channel.write_all path ~data:"a;b;c\n1;NA;x\n2;3;-\n3;4;z\n";
+    Data_frame.read_csv_exn
+      ~separator:';'
+      ~null_values:(All_columns [ "NA"; "-" ])
+      ~n_rows:2
+      ~columns:[ "a"; "b" ]
+      path
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 2)
+      ┌─────┬──────┐
+      │ a   ┆ b    │
+      │ --- ┆ ---  │
+      │ i64 ┆ i64  │
+      ╞═════╪══════╡
+      │ 1   ┆ null │
+      │ 2   ┆ 3    │
+      └─────┴──────┘ |}];
+    let path = temp_dir ^/ "headerless.csv" in
+    Out_channel.write_all path ~data:"1,2\n3,4\n";
+    Data_frame.read_csv_exn ~has_header:false path |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 2)
+      ┌──────────┬──────────┐
+      │ column_1 ┆ column_2 │
+      │ ---      ┆ ---      │
+      │ i64      ┆ i64      │
+      ╞══════════╪══════════╡
+      │ 1        ┆ 2        │
+      │ 3        ┆ 4        │
+      └──────────┴──────────┘ |}])
+;;
+
+let%expect_test "write_csv options" =
+  Filename_extended.with_temp_dir "polars-ocaml" "csv" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "out.csv" in
+    let df =
+      Data_frame.create_exn
+        Series.[ int "a" [ 1; 2 ]; float "b" [ 1.; 2.5 ]; string "c" [ "x"; "y" ] ]
+    in
+    Data_frame.write_csv_exn
+      ~has_header:false
+      ~separator:';'
+      ~quote_style:`Non_numeric
+      ~float_precision:2
+      df
+      path;
+    In_channel.read_all path |> print_string;
+    [%expect {|
+      1;1.00;"x"
+      2;2.50;"y" |}])
+;;
+
+let%expect_test "decimal columns" =
+  (* Prices in cents, i.e. 19.99, 2.50 and 10.01 *)
+  let price : int Data_type.Typed.t = Decimal (Some 10, 2) in
+  let df = Data_frame.create_exn [ Series.create price "price" [ 1999; 250; 1001 ] ] in
+  let print_prices df =
+    let series = Data_frame.column_exn df ~name:"price" in
+    print_s
+      [%message
+        ""
+          ~dtype:(Series.dtype series : Data_type.t)
+          ~values:(Series.to_list price series : int list)]
+  in
+  Filename_extended.with_temp_dir "polars-ocaml" "decimal" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "prices.csv" in
+    Data_frame.write_csv_exn df path;
+    In_channel.read_all path |> print_string;
+    [%expect {|
+      price
+      19.99
+      2.50
+      10.01 |}];
+    Data_frame.read_csv_exn
+      ~schema:(Schema.create [ "price", Decimal (Some 10, Some 2) ])
+      path
+    |> print_prices;
+    [%expect {| ((dtype (Decimal (10) (2))) (values (1999 250 1001))) |}];
+    let path = temp_dir ^/ "prices.parquet" in
+    let (_ : int) = Data_frame.write_parquet_exn df path in
+    Data_frame.read_parquet_exn path |> print_prices;
+    [%expect {| ((dtype (Decimal (10) (2))) (values (1999 250 1001))) |}])
+;;
+
+let%expect_test "decimal CSV round trip keeps signs" =
+  let price : int Data_type.Typed.t = Decimal (Some 10, 2) in
+  let df =
+    Data_frame.create_exn [ Series.create price "price" [ -50; -1999; 5; 0; 12345 ] ]
+  in
+  let print_prices df =
+    Data_frame.column_exn df ~name:"price"
+    |> Series.to_list price
+    |> [%sexp_of: int list]
+    |> print_s
+  in
+  Filename_extended.with_temp_dir "polars-ocaml" "decimal" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "prices.csv" in
+    Data_frame.write_csv_exn df path;
+    In_channel.read_all path |> print_string;
+    [%expect {|
+      price
+      -0.50
+      -19.99
+      0.05
+      0.00
+      123.45 |}];
+    let schema = Schema.create [ "price", Decimal (Some 10, Some 2) ] in
+    Data_frame.read_csv_exn ~schema path |> print_prices;
+    [%expect {| (-50 -1999 5 0 12345) |}];
+    Lazy_frame.scan_csv_exn ~dtypes:schema path |> Lazy_frame.collect_exn |> print_prices;
+    [%expect {| (-50 -1999 5 0 12345) |}];
+    Out_channel.write_all path ~data:"price\n1.234\n";
+    (match Data_frame.read_csv ~schema path with
+     | Ok _ -> print_endline "ok"
+     | Error error -> print_endline error);
+    [%expect {| could not parse "1.234" in column price as a decimal with scale 2 |}])
+;;
+
+let%expect_test "write_parquet options" =
+  Filename_extended.with_temp_dir "polars-ocaml" "parquet" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "out.parquet" in
+    let df = Data_frame.create_exn Series.[ int "a" [ 1; 2; 3 ] ] in
+    let file_size =
+      Data_frame.write_parquet_exn
+        ~compression:(Gzip (Some 9))
+        ~statistics:true
+        ~row_group_size:1
+        df
+        path
+    in
+    printf "%b" (file_size = String.length (In_channel.read_all path));
+    [%expect {| true |}];
+    Data_frame.read_parquet_exn path |> Data_frame.print;
+    [%expect
+      {|
+      shape: (3, 1)
+      ┌─────┐
+      │ a   │
+      │ --- │
+      │ i64 │
+      ╞═════╡
+      │ 1   │
+      │ 2   │
+      │ 3   │
+      └─────┘ |}])
+;;
+
+let%expect_test "read_parquet and scan_parquet options" =
+  Filename_extended.with_temp_dir "polars-ocaml" "parquet" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "wide.parquet" in
+    let df =
+      Data_frame.create_exn
+        Series.
+          [ int "a" [ 1; 2; 3 ]; string "b" [ "x"; "y"; "z" ]; float "c" [ 1.; 2.; 3. ] ]
+    in
+    let (_ : int) = Data_frame.write_parquet_exn df path in
+    Data_frame.read_parquet_exn
+      ~columns:[ "a"; "c" ]
+      ~n_rows:2
+      ~row_count:("row", 10)
+      ~parallel:`Columns
+      path
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 3)
+      ┌─────┬─────┬─────┐
+      │ row ┆ a   ┆ c   │
+      │ --- ┆ --- ┆ --- │
+      │ u32 ┆ i64 ┆ f64 │
+      ╞═════╪═════╪═════╡
+      │ 10  ┆ 1   ┆ 1.0 │
+      │ 11  ┆ 2   ┆ 2.0 │
+      └─────┴─────┴─────┘ |}];
+    Data_frame.read_parquet_exn ~projection:[ 1 ] ~parallel:`None path
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (3, 1)
+      ┌─────┐
+      │ b   │
+      │ --- │
+      │ str │
+      ╞═════╡
+      │ x   │
+      │ y   │
+      │ z   │
+      └─────┘ |}];
+    Lazy_frame.scan_parquet_exn
+      ~n_rows:1
+      ~row_count:("row", 0)
+      ~parallel:`Row_groups
+      ~low_memory:true
+      ~cache:false
+      path
+    |> Lazy_frame.select ~exprs:Expr.[ col "row"; col "b" ]
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (1, 2)
+      ┌─────┬─────┐
+      │ row ┆ b   │
+      │ --- ┆ --- │
+      │ u32 ┆ str │
+      ╞═════╪═════╡
+      │ 0   ┆ x   │
+      └─────┴─────┘ |}])
+;;
+
+let%expect_test "ipc" =
+  Filename_extended.with_temp_dir "polars-ocaml" "ipc" ~f:(fun temp_dir ->
+    let df =
+      Data_frame.create_exn
+        Series.
+          [ int "a" [ 1; 2; 3 ]; string "b" [ "x"; "y"; "z" ]; float "c" [ 1.; 2.; 3. ] ]
+    in
+    List.iter [ None; Some `Lz4; Some `Zstd ] ~f:(fun compression ->
+      let path = temp_dir ^/ "out.arrow" in
+      Data_frame.write_ipc_exn ?compression df path;
+      Data_frame.read_ipc_exn path
+      |> Data_frame.to_string_hum
+      |> String.equal (Data_frame.to_string_hum df)
+      |> printf "%b ");
+    [%expect {| true true true |}];
+    let path = temp_dir ^/ "out.arrow" in
+    Data_frame.read_ipc_exn
+      ~projection:[ 0; 2 ]
+      ~n_rows:2
+      ~row_count:("row", 0)
+      ~memory_map:false
+      path
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 3)
+      ┌─────┬─────┬─────┐
+      │ row ┆ a   ┆ c   │
+      │ --- ┆ --- ┆ --- │
+      │ u32 ┆ i64 ┆ f64 │
+      ╞═════╪═════╪═════╡
+      │ 0   ┆ 1   ┆ 1.0 │
+      │ 1   ┆ 2   ┆ 2.0 │
+      └─────┴─────┴─────┘ |}];
+    Lazy_frame.scan_ipc_exn ~n_rows:1 path
+    |> Lazy_frame.select ~exprs:Expr.[ col "b" ]
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (1, 1)
+      ┌─────┐
+      │ b   │
+      │ --- │
+      │ str │
+      ╞═════╡
+      │ x   │
+      └─────┘ |}])
+;;
+
+let%expect_test "ipc_stream" =
+  let read_fd, write_fd = Core_unix.pipe () in
+  List.iter [ 1; 2 ] ~f:(fun n ->
+    let df = Data_frame.create_exn Series.[ int "n" (List.init n ~f:Fn.id) ] in
+    Data_frame.write_ipc_stream_exn ~compression:`Lz4 df write_fd);
+  Core_unix.close write_fd;
+  let rec loop () =
+    match Data_frame.read_ipc_stream_exn read_fd with
+    | None -> print_endline "EOF"
+    | Some df ->
+      Data_frame.print df;
+      loop ()
+  in
+  loop ();
+  Core_unix.close read_fd;
+  [%expect
+    {|
+    shape: (1, 1)
+    ┌─────┐
+    │ n   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 0   │
+    └─────┘
+    shape: (2, 1)
+    ┌─────┐
+    │ n   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 0   │
+    │ 1   │
+    └─────┘
+    EOF |}]
+;;
+
+let%expect_test "ipc_stream with n_rows" =
+  let read_fd, write_fd = Core_unix.pipe () in
+  List.iter [ 3; 1 ] ~f:(fun n ->
+    let df = Data_frame.create_exn Series.[ int "n" (List.init n ~f:Fn.id) ] in
+    Data_frame.write_ipc_stream_exn df write_fd);
+  Core_unix.close write_fd;
+  (* Each call still consumes a whole stream, so the second one starts at the
+     second stream's schema message. *)
+  let rec loop () =
+    match Data_frame.read_ipc_stream_exn ~n_rows:2 read_fd with
+    | None -> print_endline "EOF"
+    | Some df ->
+      print_s [%message "" ~height:(Data_frame.height df : int)];
+      loop ()
+  in
+  loop ();
+  Core_unix.close read_fd;
+  [%expect
+    {|
+    (height 2)
+    (height 1)
+    EOF |}]
+;;
+
+let%expect_test "in-memory readers and writers" =
+  let df =
+    Data_frame.create_exn
+      Series.[ int "a" [ 1; 2 ]; string "b" [ "x"; "y" ]; float "c" [ 0.5; 1.5 ] ]
+  in
+  let round_trip name ~write ~read =
+    let buffer = write df in
+    List.iter
+      [ `String buffer
+      ; `Bytes (Bytes.of_string buffer)
+      ; `Bigstring (Bigstring.of_string buffer)
+      ]
+      ~f:(fun buffer ->
+        let df' = read buffer in
+        printf
+          "%s: %b\n"
+          name
+          (String.equal (Data_frame.to_string_hum df) (Data_frame.to_string_hum df')))
+  in
+  round_trip
+    "csv"
+    ~write:Data_frame.write_csv_to_string_exn
+    ~read:Data_frame.read_csv_from_memory_exn;
+  round_trip
+    "parquet"
+    ~write:Data_frame.write_parquet_to_string_exn
+    ~read:Data_frame.read_parquet_from_memory_exn;
+  round_trip
+    "json"
+    ~write:Data_frame.write_json_to_string_exn
+    ~read:Data_frame.read_json_from_memory_exn;
+  round_trip
+    "jsonl"
+    ~write:Data_frame.write_jsonl_to_string_exn
+    ~read:Data_frame.read_jsonl_from_memory_exn;
+  round_trip
+    "ipc"
+    ~write:Data_frame.write_ipc_to_string_exn
+    ~read:Data_frame.read_ipc_from_memory_exn;
+  [%expect
+    {|
+    csv: true
+    csv: true
+    csv: true
+    parquet: true
+    parquet: true
+    parquet: true
+    json: true
+    json: true
+    json: true
+    jsonl: true
+    jsonl: true
+    jsonl: true
+    ipc: true
+    ipc: true
+    ipc: true |}];
+  Data_frame.write_csv_to_string_exn df |> print_string;
+  [%expect {|
+    a,b,c
+    1,x,0.5
+    2,y,1.5 |}];
+  Data_frame.read_csv_from_memory_exn ~separator:'|' (`String "a|b\n1|2\n")
+  |> Data_frame.print;
+  [%expect
+    {|
+    shape: (1, 2)
+    ┌─────┬─────┐
+    │ a   ┆ b   │
+    │ --- ┆ --- │
+    │ i64 ┆ i64 │
+    ╞═════╪═════╡
+    │ 1   ┆ 2   │
+    └─────┴─────┘ |}]
+;;
+
+let%expect_test "sink_parquet and sink_ipc" =
+  Filename_extended.with_temp_dir "polars-ocaml" "sink" ~f:(fun temp_dir ->
+    let input = temp_dir ^/ "input.parquet" in
+    let (_ : int) =
+      Data_frame.create_exn
+        Series.[ int "a" [ 1; 2; 3; 4 ]; string "b" [ "w"; "x"; "y"; "z" ] ]
+      |> Data_frame.write_parquet_exn input
+    in
+    let query () =
+      Lazy_frame.scan_parquet_exn input
+      |> Lazy_frame.filter ~predicate:Expr.(col "a" > int 2)
+    in
+    let parquet = temp_dir ^/ "output.parquet" in
+    Lazy_frame.sink_parquet_exn ~compression:Snappy ~row_group_size:1 (query ()) parquet;
+    Data_frame.read_parquet_exn parquet |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 2)
+      ┌─────┬─────┐
+      │ a   ┆ b   │
+      │ --- ┆ --- │
+      │ i64 ┆ str │
+      ╞═════╪═════╡
+      │ 3   ┆ y   │
+      │ 4   ┆ z   │
+      └─────┴─────┘ |}];
+    let ipc = temp_dir ^/ "output.arrow" in
+    Lazy_frame.sink_ipc_exn ~compression:`Zstd (query ()) ipc;
+    Data_frame.read_ipc_exn ipc |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 2)
+      ┌─────┬─────┐
+      │ a   ┆ b   │
+      │ --- ┆ --- │
+      │ i64 ┆ str │
+      ╞═════╪═════╡
+      │ 3   ┆ y   │
+      │ 4   ┆ z   │
+      └─────┴─────┘ |}])
+;;
+
+let%expect_test "scan_csv options" =
+  Filename_extended.with_temp_dir "polars-ocaml" "csv" ~f:(fun temp_dir ->
+    let path = temp_dir ^/ "scan.csv" in
+    Out_channel.write_all
+      path
+      ~data:
+        "# generated\nid|score|when\n1|NA|2024-01-01\n2|2.5|2024-01-02\n3|4|2024-01-03\n";
+    Lazy_frame.scan_csv_exn
+      ~dtypes:(Schema.create [ "score", Float32 ])
+      ~separator:'|'
+      ~skip_rows:1
+      ~null_values:(All_columns_single "NA")
+      ~try_parse_dates:true
+      ~row_count:("row", 1)
+      ~cache:false
+      path
+    |> Lazy_frame.filter ~predicate:Expr.(col "id" > int 1)
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 4)
+      ┌─────┬─────┬───────┬────────────┐
+      │ row ┆ id  ┆ score ┆ when       │
+      │ --- ┆ --- ┆ ---   ┆ ---        │
+      │ u32 ┆ i64 ┆ f32   ┆ date       │
+      ╞═════╪═════╪═══════╪════════════╡
+      │ 2   ┆ 2   ┆ 2.5   ┆ 2024-01-02 │
+      │ 3   ┆ 3   ┆ 4.0   ┆ 2024-01-03 │
+      └─────┴─────┴───────┴────────────┘ |}];
+    Lazy_frame.scan_csv_exn
+      ~schema:(Schema.create [ "x", Int32; "y", Utf8; "z", Utf8 ])
+      ~separator:'|'
+      ~skip_rows:2
+      ~has_header:false
+      ~n_rows:1
+      path
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (1, 3)
+      ┌─────┬─────┬────────────┐
+      │ x   ┆ y   ┆ z          │
+      │ --- ┆ --- ┆ ---        │
+      │ i32 ┆ str ┆ str        │
+      ╞═════╪═════╪════════════╡
+      │ 1   ┆ NA  ┆ 2024-01-01 │
+      └─────┴─────┴────────────┘ |}])
+;;
+
+let%expect_test "hive-partitioned scans" =
+  Filename_extended.with_temp_dir "polars-ocaml" "hive" ~f:(fun temp_dir ->
+    List.iter
+      [ 2023, "a", [ 1; 2 ]; 2024, "a", [ 3 ]; 2024, "b", [ 4; 5 ] ]
+      ~f:(fun (year, region, values) ->
+        let dir = temp_dir ^/ sprintf "year=%d" year ^/ sprintf "region=%s" region in
+        Core_unix.mkdir_p dir;
+        let df = Data_frame.create_exn Series.[ int "value" values ] in
+        let (_ : int) = Data_frame.write_parquet_exn df (dir ^/ "part-0.parquet") in
+        ());
+    Lazy_frame.scan_parquet_exn ~hive_partitioning:true (temp_dir ^/ "*/*/*.parquet")
+    |> Lazy_frame.sort ~by_column:"value"
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (5, 3)
+      ┌───────┬──────┬────────┐
+      │ value ┆ year ┆ region │
+      │ ---   ┆ ---  ┆ ---    │
+      │ i64   ┆ i64  ┆ str    │
+      ╞═══════╪══════╪════════╡
+      │ 1     ┆ 2023 ┆ a      │
+      │ 2     ┆ 2023 ┆ a      │
+      │ 3     ┆ 2024 ┆ a      │
+      │ 4     ┆ 2024 ┆ b      │
+      │ 5     ┆ 2024 ┆ b      │
+      └───────┴──────┴────────┘ |}];
+    Lazy_frame.scan_parquet_paths_exn
+      ~hive_partitioning:true
+      ~row_count:("row", 0)
+      [ temp_dir ^/ "year=2023/*/*.parquet"; temp_dir ^/ "year=2024/*/*.parquet" ]
+    |> Lazy_frame.filter
+         ~predicate:Expr.(col "year" = int 2024 && col "region" = string "b")
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (2, 4)
+      ┌─────┬───────┬──────┬────────┐
+      │ row ┆ value ┆ year ┆ region │
+      │ --- ┆ ---   ┆ ---  ┆ ---    │
+      │ u32 ┆ i64   ┆ i64  ┆ str    │
+      ╞═════╪═══════╪══════╪════════╡
+      │ 3   ┆ 4     ┆ 2024 ┆ b      │
+      │ 4   ┆ 5     ┆ 2024 ┆ b      │
+      └─────┴───────┴──────┴────────┘ |}];
+    (* Files in partitions excluded by a filter are never read, even when the filter
+       also refers to other columns. *)
+    let dir = temp_dir ^/ "year=2025/region=a" in
+    Core_unix.mkdir_p dir;
+    Out_channel.write_all (dir ^/ "part-0.parquet") ~data:"not a parquet file";
+    let scan ?(predicate = Expr.bool true) () =
+      Lazy_frame.scan_parquet_exn ~hive_partitioning:true (temp_dir ^/ "*/*/*.parquet")
+      |> Lazy_frame.filter ~predicate
+      |> Lazy_frame.sort ~by_column:"value"
+      |> Lazy_frame.collect
+      |> Result.map ~f:(fun df ->
+        Data_frame.column_exn df ~name:"value" |> Series.to_list Int64)
+    in
+    scan ~predicate:Expr.(col "year" < int 2025) ()
+    |> [%sexp_of: (int list, string) Result.t]
+    |> print_s;
+    [%expect {| (Ok (1 2 3 4 5)) |}];
+    scan ~predicate:Expr.(col "value" > int 1 && col "year" <> int 2025) ()
+    |> [%sexp_of: (int list, string) Result.t]
+    |> print_s;
+    [%expect {| (Ok (2 3 4 5)) |}];
+    scan ~predicate:Expr.(col "region" = string "b" && col "value" > int 4) ()
+    |> [%sexp_of: (int list, string) Result.t]
+    |> print_s;
+    [%expect {| (Ok (5)) |}];
+    scan ()
+    |> Result.map_error ~f:(String.is_substring ~substring:"year=2025")
+    |> Result.ignore_m
+    |> [%sexp_of: (unit, bool) Result.t]
+    |> print_s;
+    [%expect {| (Error true) |}])
+;;
+
+let%expect_test "hive partition types" =
+  Filename_extended.with_temp_dir "polars-ocaml" "hive" ~f:(fun temp_dir ->
+    List.iter
+      [ "01", "0.5", "a", 1; "10", "1", "__HIVE_DEFAULT_PARTITION__", 2 ]
+      ~f:(fun (month, weight, label, value) ->
+        let dir =
+          temp_dir
+          ^/ sprintf "month=%s" month
+          ^/ sprintf "weight=%s" weight
+          ^/ sprintf "label=%s" label
+        in
+        Core_unix.mkdir_p dir;
+        let df = Data_frame.create_exn Series.[ int "value" [ value ] ] in
+        let (_ : int) = Data_frame.write_parquet_exn df (dir ^/ "part-0.parquet") in
+        ());
+    let scan ?hive_schema () =
+      Lazy_frame.scan_parquet ~hive_partitioning:true ?hive_schema (temp_dir ^/ "*/*/*/*")
+      |> Result.bind ~f:(fun lazy_frame ->
+        Lazy_frame.sort lazy_frame ~by_column:"value" |> Lazy_frame.collect)
+      |> Result.ok_or_failwith
+      |> Data_frame.print
+    in
+    scan ();
+    [%expect
+      {|
+      shape: (2, 4)
+      ┌───────┬───────┬────────┬───────┐
+      │ value ┆ month ┆ weight ┆ label │
+      │ ---   ┆ ---   ┆ ---    ┆ ---   │
+      │ i64   ┆ i64   ┆ f64    ┆ str   │
+      ╞═══════╪═══════╪════════╪═══════╡
+      │ 1     ┆ 1     ┆ 0.5    ┆ a     │
+      │ 2     ┆ 10    ┆ 1.0    ┆ null  │
+      └───────┴───────┴────────┴───────┘ |}];
+    scan ~hive_schema:(Schema.create [ "month", Utf8 ]) ();
+    [%expect
+      {|
+      shape: (2, 4)
+      ┌───────┬───────┬────────┬───────┐
+      │ value ┆ month ┆ weight ┆ label │
+      │ ---   ┆ ---   ┆ ---    ┆ ---   │
+      │ i64   ┆ str   ┆ f64    ┆ str   │
+      ╞═══════╪═══════╪════════╪═══════╡
+      │ 1     ┆ 01    ┆ 0.5    ┆ a     │
+      │ 2     ┆ 10    ┆ 1.0    ┆ null  │
+      └───────┴───────┴────────┴───────┘ |}];
+    Lazy_frame.scan_parquet
+      ~hive_schema:(Schema.create [ "month", Utf8 ])
+      (temp_dir ^/ "*/*/*/*")
+    |> Result.map ~f:(fun (_ : Lazy_frame.t) -> ())
+    |> [%sexp_of: (unit, string) Result.t]
+    |> print_s;
+    [%expect {| (Error "a Hive schema requires Hive partitioning to be enabled") |}])
+;;
+
+let%expect_test "write_partitioned" =
+  Filename_extended.with_temp_dir "polars-ocaml" "hive" ~f:(fun temp_dir ->
+    let df =
+      Data_frame.create_exn
+        Series.
+          [ int "year" [ 2023; 2024; 2023; 2024 ]
+          ; string "region" [ "a"; "a"; "b"; "a" ]
+          ; int "value" [ 1; 2; 3; 4 ]
+          ]
+    in
+    Data_frame.write_partitioned_exn df ~partition_by:[ "year"; "region" ] temp_dir
+    |> List.iter ~f:(fun (path, rows) ->
+      let path = String.chop_prefix_exn path ~prefix:temp_dir in
+      print_s [%message path (rows : int)]);
+    [%expect
+      {|
+      (/year=2023/region=a/part-0.parquet (rows 1))
+      (/year=2024/region=a/part-0.parquet (rows 2))
+      (/year=2023/region=b/part-0.parquet (rows 1)) |}];
+    Lazy_frame.scan_parquet_exn ~hive_partitioning:true (temp_dir ^/ "*/*/*.parquet")
+    |> Lazy_frame.sort ~by_column:"value"
+    |> Lazy_frame.collect_exn
+    |> Data_frame.print;
+    [%expect
+      {|
+      shape: (4, 3)
+      ┌───────┬──────┬────────┐
+      │ value ┆ year ┆ region │
+      │ ---   ┆ ---  ┆ ---    │
+      │ i64   ┆ i64  ┆ str    │
+      ╞═══════╪══════╪════════╡
+      │ 1     ┆ 2023 ┆ a      │
+      │ 2     ┆ 2024 ┆ a      │
+      │ 3     ┆ 2023 ┆ b      │
+      │ 4     ┆ 2024 ┆ a      │
+      └───────┴──────┴────────┘ |}];
+    Data_frame.lazy_ df
+    |> Lazy_frame.write_partitioned_exn ~format:`Csv ~partition_by:[ "region" ] temp_dir
+    |> List.map ~f:(fun (path, _) -> In_channel.read_all path)
+    |> List.iter ~f:print_string;
+    [%expect
+      {|
+      year,value
+      2023,1
+      2024,2
+      2024,4
+      year,value
+      2023,3 |}];
+    (* Writing to the same root again adds files instead of overwriting them. *)
+    Data_frame.write_partitioned_exn df ~partition_by:[ "year"; "region" ] temp_dir
+    |> List.iter ~f:(fun (path, rows) ->
+      let path = String.chop_prefix_exn path ~prefix:temp_dir in
+      print_s [%message path (rows : int)]);
+    [%expect
+      {|
+      (/year=2023/region=a/part-1.parquet (rows 1))
+      (/year=2024/region=a/part-1.parquet (rows 2))
+      (/year=2023/region=b/part-1.parquet (rows 1)) |}];
+    Lazy_frame.scan_parquet_exn ~hive_partitioning:true (temp_dir ^/ "*/*/*.parquet")
+    |> Lazy_frame.collect_exn
+    |> Data_frame.height
+    |> printf "%d";
+    [%expect {| 8 |}];
+    (* Categorical values name directories just like strings. *)
+    Data_frame.with_columns_exn df ~exprs:Expr.[ col "region" |> cast ~to_:Categorical ]
+    |> Data_frame.write_partitioned_exn
+         ~partition_by:[ "region" ]
+         (temp_dir ^/ "categorical")
+    |> List.iter ~f:(fun (path, _rows) ->
+      print_endline (String.chop_prefix_exn path ~prefix:temp_dir));
+    [%expect
+      {|
+      /categorical/region=a/part-0.parquet
+      /categorical/region=b/part-0.parquet |}];
+    (* Parquet options are passed on to every file written. *)
+    let df =
+      Data_frame.create_exn
+        Series.
+          [ string "region" (List.init 10_000 ~f:(fun i -> Bool.to_string (i % 2 = 0)))
+          ; string "label" (List.init 10_000 ~f:(sprintf "label %d"))
+          ]
+    in
+    let total_size write_partitioned ~compression directory =
+      write_partitioned ~compression (temp_dir ^/ directory)
+      |> List.sum (module Int) ~f:(fun (path, _rows) ->
+        Int64.to_int_exn (Core_unix.stat path).st_size)
+    in
+    List.iter
+      [ ( "Data_frame"
+        , fun ~compression root ->
+            Data_frame.write_partitioned_exn
+              ~compression
+              df
+              ~partition_by:[ "region" ]
+              root )
+      ; ( "Lazy_frame"
+        , fun ~compression root ->
+            Lazy_frame.write_partitioned_exn
+              ~compression
+              (Data_frame.lazy_ df)
+              ~partition_by:[ "region" ]
+              root )
+      ]
+      ~f:(fun (name, write_partitioned) ->
+        let uncompressed =
+          total_size
+            write_partitioned
+            ~compression:Parquet_compression.Uncompressed
+            (name ^ "-uncompressed")
+        in
+        let compressed =
+          total_size
+            write_partitioned
+            ~compression:(Parquet_compression.Zstd None)
+            (name ^ "-zstd")
+        in
+        print_s [%message name (compressed < uncompressed : bool)]);
+    [%expect
+      {|
+      (Data_frame ("compressed < uncompressed" true))
+      (Lazy_frame ("compressed < uncompressed" true)) |}])
+;;
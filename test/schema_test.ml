@@ -31,6 +31,9 @@ let%expect_test "check serializations" =
       ; Struct
           (List.map basic_types ~f:(fun data_type ->
              Data_type.to_string data_type, data_type))
+      ; Categorical
+      ; Decimal (Some 10, Some 2)
+      ; Array (Int64, 3)
       ; Unknown
       ]
     |> List.map ~f:(fun data_type ->
@@ -56,5 +59,6 @@ let%expect_test "check serializations" =
         (UInt64 UInt64) (Int8 Int8) (Int16 Int16) (Int32 Int32) (Int64 Int64)
         (Float32 Float32) (Float64 Float64) (Utf8 Utf8) (Binary Binary)
         (Date Date))))
-     (Unknown Unknown)) |}]
+     (Categorical Categorical) ("(Decimal(10)(2))" (Decimal (10) (2)))
+     ("(Array Int64 3)" (Array Int64 3)) (Unknown Unknown)) |}]
 ;;
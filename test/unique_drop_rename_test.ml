@@ -0,0 +1,159 @@
+open! Core
+open! Polars
+
+let df =
+  Data_frame.create_exn
+    [ Series.int "id" [ 1; 1; 2; 3; 3 ]
+    ; Series.stringo "label" [ Some "a"; Some "b"; None; Some "c"; Some "c" ]
+    ]
+;;
+
+let print_rows df =
+  print_s
+    [%message
+      ""
+        ~columns:(Data_frame.get_column_names df : string list)
+        ~rows:(Data_frame.iter_rows df |> Sequence.to_list : Any_value.t array list)]
+;;
+
+let print_error = function
+  | Ok _ -> print_endline "ok"
+  | Error error -> print_endline error
+;;
+
+let%expect_test "unique" =
+  List.iter [ `First; `Last; `None ] ~f:(fun keep ->
+    Data_frame.unique_exn ~subset:[ "id" ] ~keep ~maintain_order:true df |> print_rows);
+  [%expect
+    {|
+    ((columns (id label))
+     (rows (((Int64 1) (Utf8 a)) ((Int64 2) Null) ((Int64 3) (Utf8 c)))))
+    ((columns (id label))
+     (rows (((Int64 1) (Utf8 b)) ((Int64 2) Null) ((Int64 3) (Utf8 c)))))
+    ((columns (id label)) (rows (((Int64 2) Null)))) |}];
+  Data_frame.unique_exn ~keep:`First ~maintain_order:true df |> print_rows;
+  [%expect
+    {|
+    ((columns (id label))
+     (rows
+      (((Int64 1) (Utf8 a)) ((Int64 1) (Utf8 b)) ((Int64 2) Null)
+       ((Int64 3) (Utf8 c))))) |}];
+  Data_frame.lazy_ df
+  |> Lazy_frame.unique ~subset:[ "id" ] ~keep:`Last ~maintain_order:true
+  |> Lazy_frame.collect_exn
+  |> print_rows;
+  [%expect
+    {|
+    ((columns (id label))
+     (rows (((Int64 1) (Utf8 b)) ((Int64 2) Null) ((Int64 3) (Utf8 c))))) |}]
+;;
+
+let%expect_test "drop_nulls and drop" =
+  Data_frame.drop_nulls_exn df |> Data_frame.height |> printf "%d\n";
+  [%expect {| 4 |}];
+  Data_frame.drop_nulls_exn ~subset:[ "id" ] df |> Data_frame.height |> printf "%d\n";
+  [%expect {| 5 |}];
+  Data_frame.lazy_ df
+  |> Lazy_frame.drop_nulls ~subset:[ "label" ]
+  |> Lazy_frame.collect_exn
+  |> Data_frame.height
+  |> printf "%d\n";
+  [%expect {| 4 |}];
+  Data_frame.drop_exn df ~names:[ "label" ]
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (id) |}];
+  Data_frame.lazy_ df
+  |> Lazy_frame.drop ~names:[ "id" ]
+  |> Lazy_frame.collect_exn
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (label) |}];
+  Data_frame.drop df ~names:[ "label"; "missing" ] |> print_error;
+  [%expect {| not found: missing |}];
+  Data_frame.lazy_ df
+  |> Lazy_frame.drop ~names:[ "label"; "missing" ]
+  |> Lazy_frame.collect
+  |> Result.map_error ~f:(fun error -> String.split_lines error |> List.hd_exn)
+  |> print_error;
+  [%expect {| field not found: missing |}]
+;;
+
+let%expect_test "rename" =
+  Data_frame.rename_exn df ~existing:"label" ~new_name:"name"
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (id name) |}];
+  Data_frame.rename_many_exn df ~mapping:[ "id", "label"; "label", "id" ]
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (label id) |}];
+  Data_frame.lazy_ df
+  |> Lazy_frame.rename_many ~mapping:[ "id", "label"; "label", "id" ]
+  |> Lazy_frame.collect_exn
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (label id) |}];
+  Data_frame.rename df ~existing:"id" ~new_name:"label" |> print_error;
+  [%expect {| duplicate: duplicate column names found |}];
+  Data_frame.rename df ~existing:"missing" ~new_name:"x" |> print_error;
+  [%expect {| not found: missing |}]
+;;
+
+let%expect_test "replace_column and insert_column" =
+  let series = Series.int "x" [ 10; 20; 30; 40; 50 ] in
+  Data_frame.replace_column_exn df ~index:1 ~series
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (id x) |}];
+  Data_frame.replace_column_exn df ~index:0 ~series:(Series.int "id" [ 5; 4; 3; 2; 1 ])
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (id label) |}];
+  Data_frame.replace_column df ~index:1 ~series:(Series.int "id" [ 5; 4; 3; 2; 1 ])
+  |> print_error;
+  [%expect {| duplicate: column with name "id" is already present in the dataframe |}];
+  Data_frame.insert_column_exn df ~index:0 ~series
+  |> Data_frame.get_column_names
+  |> [%sexp_of: string list]
+  |> print_s;
+  [%expect {| (x id label) |}];
+  Data_frame.insert_column df ~index:3 ~series |> print_error;
+  [%expect {| unable to insert at index 3, the dataframe has only 2 columns |}];
+  Data_frame.insert_column df ~index:1 ~series:(Series.int "x" [ 1 ]) |> print_error;
+  [%expect
+    {|
+    lengths don't match: unable to add a column of length 1 to a dataframe of height 5 |}];
+  Data_frame.insert_column df ~index:1 ~series:(Series.int "id" [ 1; 2; 3; 4; 5 ])
+  |> print_error;
+  [%expect {| duplicate: column with name "id" is already present in the dataframe |}]
+;;
+
+let%expect_test "Lazy_frame.replace_column and insert_column" =
+  let lazy_frame = Data_frame.lazy_ df in
+  Lazy_frame.replace_column_exn
+    lazy_frame
+    ~index:0
+    ~expr:Expr.(col "id" * int 10 |> alias ~name:"x10")
+  |> Lazy_frame.insert_column_exn ~index:2 ~expr:Expr.(col "id")
+  |> Lazy_frame.collect_exn
+  |> print_rows;
+  [%expect
+    {|
+    ((columns (x10 label id))
+     (rows
+      (((Int64 10) (Utf8 a) (Int64 1)) ((Int64 10) (Utf8 b) (Int64 1))
+       ((Int64 20) Null (Int64 2)) ((Int64 30) (Utf8 c) (Int64 3))
+       ((Int64 30) (Utf8 c) (Int64 3))))) |}];
+  Lazy_frame.replace_column lazy_frame ~index:2 ~expr:Expr.(col "id") |> print_error;
+  [%expect {| unable to replace at index 2, the dataframe has only 2 columns |}];
+  Lazy_frame.insert_column lazy_frame ~index:3 ~expr:Expr.(col "id") |> print_error;
+  [%expect {| unable to insert at index 3, the dataframe has only 2 columns |}]
+;;